@@ -1,5 +1,13 @@
-use crate::error::{CustomErrors, MismatchError};
-use crate::matrix::Matrix;
+use crate::error::{
+    CustomErrors, EmptyVectorError, MismatchError, NotConvergedError, NotImplementedError,
+    SingularMatrixError,
+};
+use crate::matrix::{
+    decomposition::lu_decompose,
+    logic::is_square,
+    operations::{multiply_matrices, multiply_matrix_vector},
+    Matrix,
+};
 use crate::vector::{operations, util};
 
 pub fn solve_system<T: std::marker::Copy + Into<f64>>(
@@ -9,9 +17,12 @@ pub fn solve_system<T: std::marker::Copy + Into<f64>>(
     let n = coeff_matrix.n;
     let zero_f64: f64 = 0.0;
 
-    let rows = coeff_matrix.rows;
+    let rows = coeff_matrix.rows();
     if m + 1 != n {
-        return Err(CustomErrors::Mismatch(MismatchError));
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (m, m + 1),
+            (m, n),
+        )));
     }
 
     // unpack matrix into variable coeff matrix and
@@ -123,14 +134,402 @@ pub fn solve_system<T: std::marker::Copy + Into<f64>>(
     Ok(solve_row)
 }
 
+// pivots at or below this are treated as zero, relative to the largest entry
+// so that small but well-conditioned matrices keep their pivots
+fn pivot_tolerance(matrix: &Matrix<f64>) -> f64 {
+    let scale = matrix.data.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+    1e-10 * scale
+}
+
+pub fn rref(matrix: &Matrix<f64>) -> Matrix<f64> {
+    let m = matrix.m;
+    let n = matrix.n;
+    let tolerance = pivot_tolerance(matrix);
+    let mut rows = matrix.rows();
+
+    let mut pivot_row = 0;
+    for col in 0..n {
+        if pivot_row == m {
+            break;
+        }
+
+        // partial pivoting, largest magnitude entry at or below the pivot row
+        let mut max_row = pivot_row;
+        for i in pivot_row + 1..m {
+            if rows[i][col].abs() > rows[max_row][col].abs() {
+                max_row = i
+            }
+        }
+
+        if rows[max_row][col].abs() <= tolerance {
+            // no usable pivot in this column, treat what's left as zero
+            for i in pivot_row..m {
+                rows[i][col] = 0.0
+            }
+            continue;
+        }
+
+        rows.swap(pivot_row, max_row);
+
+        let pivot = rows[pivot_row][col];
+        for j in 0..n {
+            rows[pivot_row][j] /= pivot
+        }
+
+        for i in 0..m {
+            if i == pivot_row {
+                continue;
+            }
+            let factor = rows[i][col];
+            for j in 0..n {
+                rows[i][j] -= factor * rows[pivot_row][j]
+            }
+        }
+
+        pivot_row += 1
+    }
+
+    // clean up floating point noise left behind by the elimination. rows
+    // below the last pivot are nothing but noise, the pivot rows have been
+    // divided by their pivot so an unscaled cutoff applies to them
+    for i in 0..m {
+        for j in 0..n {
+            if i >= pivot_row || rows[i][j].abs() < 1e-10 {
+                rows[i][j] = 0.0
+            }
+        }
+    }
+
+    Matrix {
+        data: rows.concat(),
+        m,
+        n,
+    }
+}
+
+pub fn rank(matrix: &Matrix<f64>) -> usize {
+    let tolerance = pivot_tolerance(matrix);
+    let reduced = rref(matrix);
+
+    let mut rank = 0;
+    for i in 0..reduced.m {
+        for j in 0..reduced.n {
+            if reduced[(i, j)].abs() > tolerance {
+                rank += 1;
+                break;
+            }
+        }
+    }
+
+    rank
+}
+
+pub fn solve(a: &Matrix<f64>, b: &Vec<f64>) -> Result<Vec<f64>, CustomErrors> {
+    if !is_square(a) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
+    let n = a.n;
+    if b.len() != a.m {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (a.m, 1),
+            (b.len(), 1),
+        )));
+    }
+
+    let (lower, upper, permutation) = match lu_decompose(a) {
+        Ok(lu) => lu,
+        Err(err) => return Err(err),
+    };
+
+    // relative to the largest entry of a, like `inverse`, so small but
+    // well-conditioned systems are still solved
+    let scale = a.data.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+    let tolerance = 1e-12 * scale;
+    for i in 0..n {
+        if upper[(i, i)].abs() <= tolerance {
+            return Err(CustomErrors::SingularMatrix(SingularMatrixError));
+        }
+    }
+
+    // PAx = LUx = Pb, solve Ly = Pb then Ux = y
+    let mut permuted_b: Vec<f64> = vec![];
+    for i in 0..n {
+        permuted_b.push(b[permutation[i]])
+    }
+
+    let y = match forward_substitution(&lower, &permuted_b) {
+        Ok(y) => y,
+        Err(err) => return Err(err),
+    };
+
+    back_substitution(&upper, &y)
+}
+
+// least squares solution of an overdetermined system through the normal
+// equations (AᵀA)x = Aᵀb. squaring the condition number makes this less
+// accurate than QR for ill-conditioned a, but it reuses `solve` directly
+pub fn least_squares(a: &Matrix<f64>, b: &Vec<f64>) -> Result<Vec<f64>, CustomErrors> {
+    if b.len() != a.m {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (a.m, 1),
+            (b.len(), 1),
+        )));
+    }
+
+    let a_t = a.transpose();
+    let gram = match multiply_matrices(&a_t, a) {
+        Ok(gram) => gram,
+        Err(err) => return Err(err),
+    };
+    let moment = match multiply_matrix_vector(&a_t, b) {
+        Ok(moment) => moment,
+        Err(err) => return Err(err),
+    };
+
+    solve(&gram, &moment)
+}
+
+// Jacobi iteration, every component of the next iterate is computed from
+// the previous one only. converges for strictly diagonally dominant a, and
+// stops once no component moves by more than tolerance in a sweep
+pub fn solve_jacobi(
+    a: &Matrix<f64>,
+    b: &Vec<f64>,
+    max_iters: usize,
+    tolerance: f64,
+) -> Result<Vec<f64>, CustomErrors> {
+    match check_iterative_system(a, b) {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    };
+
+    let n = a.n;
+    let mut x: Vec<f64> = vec![0.0; n];
+    for _ in 0..max_iters {
+        let mut next: Vec<f64> = vec![0.0; n];
+        let mut change: f64 = 0.0;
+        for i in 0..n {
+            let mut sum = b[i];
+            for j in 0..n {
+                if j != i {
+                    sum -= a[(i, j)] * x[j]
+                }
+            }
+            next[i] = sum / a[(i, i)];
+            change = change.max((next[i] - x[i]).abs())
+        }
+        x = next;
+
+        // diverged past what f64 can hold, more sweeps won't help
+        if !change.is_finite() {
+            break;
+        }
+        if change < tolerance {
+            return Ok(x);
+        }
+    }
+
+    Err(CustomErrors::NotConverged(NotConvergedError))
+}
+
+// Gauss-Seidel iteration, like Jacobi but each component is updated in
+// place so the rest of the sweep already uses it. usually needs noticeably
+// fewer sweeps than Jacobi on diagonally dominant systems
+pub fn solve_gauss_seidel(
+    a: &Matrix<f64>,
+    b: &Vec<f64>,
+    max_iters: usize,
+    tolerance: f64,
+) -> Result<Vec<f64>, CustomErrors> {
+    match check_iterative_system(a, b) {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    };
+
+    let n = a.n;
+    let mut x: Vec<f64> = vec![0.0; n];
+    for _ in 0..max_iters {
+        let mut change: f64 = 0.0;
+        for i in 0..n {
+            let mut sum = b[i];
+            for j in 0..n {
+                if j != i {
+                    sum -= a[(i, j)] * x[j]
+                }
+            }
+            let updated = sum / a[(i, i)];
+            change = change.max((updated - x[i]).abs());
+            x[i] = updated
+        }
+
+        // diverged past what f64 can hold, more sweeps won't help
+        if !change.is_finite() {
+            break;
+        }
+        if change < tolerance {
+            return Ok(x);
+        }
+    }
+
+    Err(CustomErrors::NotConverged(NotConvergedError))
+}
+
+// shared checks for the iterative solvers, both divide by the diagonal
+fn check_iterative_system(a: &Matrix<f64>, b: &Vec<f64>) -> Result<(), CustomErrors> {
+    if !is_square(a) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
+    if b.len() != a.n {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (a.n, 1),
+            (b.len(), 1),
+        )));
+    }
+
+    for i in 0..a.n {
+        if a[(i, i)] == 0.0 {
+            return Err(CustomErrors::SingularMatrix(SingularMatrixError));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn forward_substitution(l: &Matrix<f64>, b: &Vec<f64>) -> Result<Vec<f64>, CustomErrors> {
+    if !is_square(l) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
+    let n = l.n;
+    if b.len() != n {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (n, 1),
+            (b.len(), 1),
+        )));
+    }
+
+    let mut x: Vec<f64> = vec![0.0; n];
+    for i in 0..n {
+        if l[(i, i)] == 0.0 {
+            return Err(CustomErrors::SingularMatrix(SingularMatrixError));
+        }
+
+        let mut sum = b[i];
+        for j in 0..i {
+            sum -= l[(i, j)] * x[j]
+        }
+        x[i] = sum / l[(i, i)]
+    }
+
+    Ok(x)
+}
+
+pub fn back_substitution(u: &Matrix<f64>, b: &Vec<f64>) -> Result<Vec<f64>, CustomErrors> {
+    if !is_square(u) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
+    let n = u.n;
+    if b.len() != n {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (n, 1),
+            (b.len(), 1),
+        )));
+    }
+
+    let mut x: Vec<f64> = vec![0.0; n];
+    for i in (0..n).rev() {
+        if u[(i, i)] == 0.0 {
+            return Err(CustomErrors::SingularMatrix(SingularMatrixError));
+        }
+
+        let mut sum = b[i];
+        for j in i + 1..n {
+            sum -= u[(i, j)] * x[j]
+        }
+        x[i] = sum / u[(i, i)]
+    }
+
+    Ok(x)
+}
+
+// Thomas algorithm for a tridiagonal system in O(n). sub and sup are the
+// n - 1 entries below and above the diagonal. there is no pivoting, so it's
+// meant for the diagonally dominant systems that come out of discretizations
+pub fn solve_tridiagonal(
+    sub: &[f64],
+    diag: &[f64],
+    sup: &[f64],
+    rhs: &[f64],
+) -> Result<Vec<f64>, CustomErrors> {
+    let n = diag.len();
+    if n == 0 {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    if rhs.len() != n {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (n, 1),
+            (rhs.len(), 1),
+        )));
+    }
+
+    for band in [sub, sup] {
+        if band.len() != n - 1 {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (n - 1, 1),
+                (band.len(), 1),
+            )));
+        }
+    }
+
+    // forward sweep, eliminating the sub diagonal
+    let mut c_prime: Vec<f64> = vec![0.0; n];
+    let mut d_prime: Vec<f64> = vec![0.0; n];
+    for i in 0..n {
+        let mut denominator = diag[i];
+        let mut d = rhs[i];
+        if i > 0 {
+            denominator -= sub[i - 1] * c_prime[i - 1];
+            d -= sub[i - 1] * d_prime[i - 1];
+        }
+
+        if denominator == 0.0 {
+            return Err(CustomErrors::SingularMatrix(SingularMatrixError));
+        }
+
+        if i < n - 1 {
+            c_prime[i] = sup[i] / denominator
+        }
+        d_prime[i] = d / denominator
+    }
+
+    let mut x: Vec<f64> = vec![0.0; n];
+    x[n - 1] = d_prime[n - 1];
+    for i in (0..n - 1).rev() {
+        x[i] = d_prime[i] - c_prime[i] * x[i + 1]
+    }
+
+    Ok(x)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        error::{CustomErrors, MismatchError},
-        matrix::Matrix,
+        error::{
+            CustomErrors, EmptyVectorError, MismatchError, NotConvergedError, NotImplementedError,
+            SingularMatrixError,
+        },
+        matrix::{logic::approx_eq, Matrix},
+        vector::util::create_identity_matrix,
     };
 
-    use super::solve_system;
+    use super::{
+        back_substitution, forward_substitution, least_squares, rank, rref, solve,
+        solve_gauss_seidel, solve_jacobi, solve_system, solve_tridiagonal,
+    };
 
     #[test]
     fn test_solve_system() {
@@ -166,8 +565,358 @@ mod tests {
                 assert!(false)
             }
             Err(err) => {
-                assert_eq!(err, CustomErrors::Mismatch(MismatchError))
+                assert_eq!(
+                    err,
+                    CustomErrors::Mismatch(MismatchError::new((3, 4), (3, 3)))
+                )
             }
         };
     }
+
+    #[test]
+    fn test_rref_full_rank() {
+        let rows: Vec<Vec<f64>> = vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ];
+        let m = Matrix::new(rows).unwrap();
+        let identity = create_identity_matrix(3).unwrap();
+
+        assert!(approx_eq(&rref(&m), &identity, 1e-9));
+
+        // entries far below the old absolute cutoff still have pivots
+        let small = m.map(|v| v * 1e-13);
+        assert!(approx_eq(&rref(&small), &identity, 1e-9))
+    }
+
+    #[test]
+    fn test_rref_rank_deficient() {
+        let rows: Vec<Vec<f64>> = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+        let m = Matrix::new(rows).unwrap();
+        let target = Matrix::new(vec![
+            vec![1.0, 0.0, -1.0],
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 0.0, 0.0],
+        ])
+        .unwrap();
+
+        assert!(approx_eq(&rref(&m), &target, 1e-9));
+
+        // non-square input
+        let rows: Vec<Vec<f64>> = vec![vec![1.0, 2.0, 3.0, 4.0], vec![2.0, 4.0, 6.0, 8.0]];
+        let m = Matrix::new(rows).unwrap();
+        let target = Matrix::new(vec![vec![1.0, 2.0, 3.0, 4.0], vec![0.0, 0.0, 0.0, 0.0]]).unwrap();
+
+        assert!(approx_eq(&rref(&m), &target, 1e-9))
+    }
+
+    #[test]
+    fn test_rank() {
+        let full_rank = Matrix::new(vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ])
+        .unwrap();
+        assert_eq!(rank(&full_rank), 3);
+
+        // third row is the sum of the first two
+        let dependent = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![0.0, 1.0, 4.0],
+            vec![1.0, 3.0, 7.0],
+        ])
+        .unwrap();
+        assert_eq!(rank(&dependent), 2);
+
+        let zero = Matrix::new(vec![vec![0.0, 0.0], vec![0.0, 0.0]]).unwrap();
+        assert_eq!(rank(&zero), 0);
+
+        // scale does not change the rank
+        let identity: Matrix<f64> = create_identity_matrix(3).unwrap();
+        assert_eq!(rank(&identity.map(|v| v * 1e-11)), 3);
+        assert_eq!(rank(&dependent.map(|v| v * 1e6)), 2);
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = Matrix::new(vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ])
+        .unwrap();
+        let b = vec![8.0, -11.0, -3.0];
+        let target = vec![2.0, 3.0, -1.0];
+
+        let x = solve(&a, &b).unwrap();
+        for i in 0..3 {
+            assert!((x[i] - target[i]).abs() < 1e-9)
+        }
+    }
+
+    #[test]
+    fn test_solve_small_entries() {
+        let identity: Matrix<f64> = create_identity_matrix(8).unwrap();
+        let a = identity.map(|v| v * 1e-13);
+        let b = vec![1e-13; 8];
+
+        let x = solve(&a, &b).unwrap();
+        for i in 0..8 {
+            assert!((x[i] - 1.0).abs() < 1e-9)
+        }
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let a = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        match solve(&a, &vec![1.0, 2.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+
+        match solve(&a, &vec![1.0, 2.0, 3.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (3, 1)))
+            ),
+        }
+
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 5.0]]).unwrap();
+        match solve(&a, &vec![1.0, 2.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+    }
+
+    #[test]
+    fn test_forward_substitution() {
+        // 2x = 4, x + y = 5, -x + 2y + 4z = 12 -> (2, 3, 2)
+        let l = Matrix::new(vec![
+            vec![2.0, 0.0, 0.0],
+            vec![1.0, 1.0, 0.0],
+            vec![-1.0, 2.0, 4.0],
+        ])
+        .unwrap();
+        let b = vec![4.0, 5.0, 12.0];
+
+        assert_eq!(forward_substitution(&l, &b).unwrap(), vec![2.0, 3.0, 2.0]);
+
+        match forward_substitution(&l, &vec![1.0, 2.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 1), (2, 1)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_back_substitution() {
+        // x + 2y - z = 2, 3y + z = 7, 2z = 4 -> (2/3, 5/3, 2)
+        let u = Matrix::new(vec![
+            vec![1.0, 2.0, -1.0],
+            vec![0.0, 3.0, 1.0],
+            vec![0.0, 0.0, 2.0],
+        ])
+        .unwrap();
+        let b = vec![2.0, 7.0, 4.0];
+        let target = vec![2.0 / 3.0, 5.0 / 3.0, 2.0];
+
+        let x = back_substitution(&u, &b).unwrap();
+        for i in 0..3 {
+            assert!((x[i] - target[i]).abs() < 1e-12)
+        }
+
+        let rectangular = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![0.0, 1.0, 2.0]]).unwrap();
+        match back_substitution(&rectangular, &vec![1.0, 2.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+    }
+
+    #[test]
+    fn test_solve_tridiagonal() {
+        // [2 -1 0 0; -1 2 -1 0; 0 -1 2 -1; 0 0 -1 2] x = b with x = [1, 2, 3, 4]
+        let sub = [-1.0, -1.0, -1.0];
+        let diag = [2.0, 2.0, 2.0, 2.0];
+        let sup = [-1.0, -1.0, -1.0];
+        let rhs = [0.0, 0.0, 0.0, 5.0];
+
+        let x = solve_tridiagonal(&sub, &diag, &sup, &rhs).unwrap();
+        let expected = [1.0, 2.0, 3.0, 4.0];
+        for i in 0..4 {
+            assert!((x[i] - expected[i]).abs() < 1e-12);
+        }
+
+        // agrees with the dense solver on an asymmetric system
+        let a = Matrix::new(vec![
+            vec![4.0, 1.0, 0.0],
+            vec![2.0, 5.0, 3.0],
+            vec![0.0, 1.0, 6.0],
+        ])
+        .unwrap();
+        let b = vec![1.0, 2.0, 3.0];
+        let x = solve_tridiagonal(&[2.0, 1.0], &[4.0, 5.0, 6.0], &[1.0, 3.0], &b).unwrap();
+        let dense = solve(&a, &b).unwrap();
+        for i in 0..3 {
+            assert!((x[i] - dense[i]).abs() < 1e-12);
+        }
+
+        assert_eq!(
+            solve_tridiagonal(&[], &[4.0], &[], &[2.0]).unwrap(),
+            vec![0.5]
+        );
+    }
+
+    #[test]
+    fn test_solve_tridiagonal_errors() {
+        match solve_tridiagonal(&[1.0], &[2.0, 2.0, 2.0], &[1.0, 1.0], &[1.0, 1.0, 1.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (1, 1)))
+            ),
+        }
+
+        match solve_tridiagonal(&[1.0], &[2.0, 2.0], &[1.0], &[1.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (1, 1)))
+            ),
+        }
+
+        match solve_tridiagonal(&[], &[], &[], &[]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+
+        match solve_tridiagonal(&[1.0], &[0.0, 1.0], &[1.0], &[1.0, 1.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+    }
+
+    #[test]
+    fn test_least_squares() {
+        // points scattered around y = 2x + 1, columns are [1, x]
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let ys = vec![1.1, 2.9, 5.2, 6.8, 9.1, 10.9];
+        let mut rows = vec![];
+        for x in xs {
+            rows.push(vec![1.0, x])
+        }
+        let a = Matrix::new(rows).unwrap();
+
+        let beta = least_squares(&a, &ys).unwrap();
+        assert!((beta[0] - 1.0).abs() < 0.1);
+        assert!((beta[1] - 2.0).abs() < 0.05);
+
+        // an exact fit is recovered exactly
+        let exact: Vec<f64> = xs.iter().map(|x| 3.0 - 0.5 * x).collect();
+        let beta = least_squares(&a, &exact).unwrap();
+        assert!((beta[0] - 3.0).abs() < 1e-10);
+        assert!((beta[1] + 0.5).abs() < 1e-10);
+
+        match least_squares(&a, &vec![1.0, 2.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((6, 1), (2, 1)))
+            ),
+        }
+
+        // repeated columns leave AᵀA singular
+        let dependent = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]).unwrap();
+        match least_squares(&dependent, &vec![1.0, 2.0, 3.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+    }
+
+    #[test]
+    fn test_solve_jacobi() {
+        // strictly diagonally dominant
+        let a = Matrix::new(vec![
+            vec![10.0, -1.0, 2.0],
+            vec![-1.0, 11.0, -1.0],
+            vec![2.0, -1.0, 10.0],
+        ])
+        .unwrap();
+        let b = vec![6.0, 25.0, -11.0];
+
+        let x = solve_jacobi(&a, &b, 100, 1e-12).unwrap();
+        let direct = solve(&a, &b).unwrap();
+        for i in 0..3 {
+            assert!((x[i] - direct[i]).abs() < 1e-10);
+        }
+
+        // the iteration matrix has spectral radius above one
+        let divergent = Matrix::new(vec![vec![1.0, 3.0], vec![2.0, 1.0]]).unwrap();
+        match solve_jacobi(&divergent, &vec![1.0, 1.0], 100, 1e-10) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotConverged(NotConvergedError)),
+        }
+
+        let zero_diagonal = Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 2.0]]).unwrap();
+        match solve_jacobi(&zero_diagonal, &vec![1.0, 1.0], 100, 1e-10) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+
+        match solve_jacobi(&a, &vec![1.0], 100, 1e-10) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 1), (1, 1)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_solve_gauss_seidel() {
+        let a = Matrix::new(vec![
+            vec![10.0, -1.0, 2.0],
+            vec![-1.0, 11.0, -1.0],
+            vec![2.0, -1.0, 10.0],
+        ])
+        .unwrap();
+        let b = vec![6.0, 25.0, -11.0];
+
+        let x = solve_gauss_seidel(&a, &b, 100, 1e-12).unwrap();
+        let direct = solve(&a, &b).unwrap();
+        for i in 0..3 {
+            assert!((x[i] - direct[i]).abs() < 1e-10);
+        }
+
+        // the fewest sweeps each method needs to reach the same tolerance
+        let mut jacobi_iters = 0;
+        while solve_jacobi(&a, &b, jacobi_iters, 1e-10).is_err() {
+            jacobi_iters += 1
+        }
+        let mut gauss_seidel_iters = 0;
+        while solve_gauss_seidel(&a, &b, gauss_seidel_iters, 1e-10).is_err() {
+            gauss_seidel_iters += 1
+        }
+        assert!(gauss_seidel_iters < jacobi_iters);
+
+        let divergent = Matrix::new(vec![vec![1.0, 3.0], vec![2.0, 1.0]]).unwrap();
+        match solve_gauss_seidel(&divergent, &vec![1.0, 1.0], 100, 1e-10) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotConverged(NotConvergedError)),
+        }
+
+        let zero_diagonal = Matrix::new(vec![vec![1.0, 1.0], vec![1.0, 0.0]]).unwrap();
+        match solve_gauss_seidel(&zero_diagonal, &vec![1.0, 1.0], 100, 1e-10) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+    }
 }
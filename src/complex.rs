@@ -0,0 +1,182 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::matrix::Matrix;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    pub fn conj(&self) -> Complex {
+        Complex {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl From<u8> for Complex {
+    fn from(value: u8) -> Complex {
+        Complex::new(value.into(), 0.0)
+    }
+}
+
+impl From<i32> for Complex {
+    fn from(value: i32) -> Complex {
+        Complex::new(value.into(), 0.0)
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(value: f64) -> Complex {
+        Complex::new(value, 0.0)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+// (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl AddAssign for Complex {
+    fn add_assign(&mut self, other: Complex) {
+        *self = *self + other
+    }
+}
+
+impl SubAssign for Complex {
+    fn sub_assign(&mut self, other: Complex) {
+        *self = *self - other
+    }
+}
+
+impl MulAssign for Complex {
+    fn mul_assign(&mut self, other: Complex) {
+        *self = *self * other
+    }
+}
+
+// the Hermitian transpose Aᴴ, element (i, j) is the conjugate of A(j, i)
+pub fn conjugate_transpose(matrix: &Matrix<Complex>) -> Matrix<Complex> {
+    matrix.transpose().map(|z| z.conj())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::operations::{get_determinant, multiply_matrices};
+    use crate::matrix::Matrix;
+
+    use super::{conjugate_transpose, Complex};
+
+    #[test]
+    fn test_conj() {
+        assert_eq!(Complex::new(1.5, -2.0).conj(), Complex::new(1.5, 2.0));
+        assert_eq!(Complex::new(3.0, 0.0).conj(), Complex::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn test_conjugate_transpose() {
+        let a = Matrix::new(vec![
+            vec![Complex::new(1.0, 2.0), Complex::new(3.0, -1.0)],
+            vec![Complex::new(0.0, 4.0), Complex::new(5.0, 0.0)],
+        ])
+        .unwrap();
+
+        let target = Matrix::new(vec![
+            vec![Complex::new(1.0, -2.0), Complex::new(0.0, -4.0)],
+            vec![Complex::new(3.0, 1.0), Complex::new(5.0, 0.0)],
+        ])
+        .unwrap();
+        assert_eq!(conjugate_transpose(&a), target);
+
+        // a Hermitian matrix is its own conjugate transpose
+        let h = Matrix::new(vec![
+            vec![Complex::new(2.0, 0.0), Complex::new(1.0, -1.0)],
+            vec![Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)],
+        ])
+        .unwrap();
+        assert_eq!(conjugate_transpose(&h), h);
+    }
+
+    #[test]
+    fn test_complex_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!(-a, Complex::new(-1.0, -2.0));
+        assert_eq!(a * a.conj(), Complex::new(5.0, 0.0));
+
+        let mut c = a;
+        c += b;
+        c -= Complex::from(1u8);
+        c *= Complex::new(0.0, 1.0);
+        assert_eq!(c, Complex::new(-1.0, 3.0));
+    }
+
+    #[test]
+    fn test_complex_matrices() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::from(1u8);
+        let a = Matrix::new(vec![vec![one, i], vec![i, one]]).unwrap();
+        let b = Matrix::new(vec![vec![one, -i], vec![-i, one]]).unwrap();
+
+        // (1 + i·i) on the diagonal and (-i + i) off it
+        let target = Matrix::new(vec![
+            vec![Complex::from(2u8), Complex::from(0u8)],
+            vec![Complex::from(0u8), Complex::from(2u8)],
+        ])
+        .unwrap();
+        assert_eq!(multiply_matrices(&a, &b).unwrap(), target);
+
+        // det = 1·1 - i·i = 2
+        assert_eq!(get_determinant(&a).unwrap(), Complex::new(2.0, 0.0));
+
+        let c = Matrix::new(vec![
+            vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)],
+            vec![Complex::new(0.0, 3.0), Complex::new(1.0, -1.0)],
+        ])
+        .unwrap();
+        // (1 + i)(1 - i) - 2·3i = 2 - 6i
+        assert_eq!(get_determinant(&c).unwrap(), Complex::new(2.0, -6.0));
+    }
+}
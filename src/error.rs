@@ -1,7 +1,16 @@
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct MismatchError;
+pub struct MismatchError {
+    pub expected: (usize, usize),
+    pub found: (usize, usize),
+}
+
+impl MismatchError {
+    pub fn new(expected: (usize, usize), found: (usize, usize)) -> MismatchError {
+        MismatchError { expected, found }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct EmptyVectorError;
@@ -18,6 +27,27 @@ pub struct NotImplementedError;
 #[derive(Debug, Clone, PartialEq)]
 pub struct SingularMatrixError;
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexOutOfBoundsError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IoError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFittedError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZeroNormError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotPositiveDefiniteError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotConvergedError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidParameterError;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum CustomErrors {
     EmptyVector(EmptyVectorError),
@@ -26,10 +56,179 @@ pub enum CustomErrors {
     BadType(BadTypeError),
     NotImplemented(NotImplementedError),
     SingularMatrix(SingularMatrixError),
+    IndexOutOfBounds(IndexOutOfBoundsError),
+    Io(IoError),
+    NotFitted(NotFittedError),
+    ZeroNorm(ZeroNormError),
+    NotPositiveDefinite(NotPositiveDefiniteError),
+    NotConverged(NotConvergedError),
+    InvalidParameter(InvalidParameterError),
+}
+
+impl fmt::Display for MismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "shape mismatch: expected {}x{}, found {}x{}",
+            self.expected.0, self.expected.1, self.found.0, self.found.1
+        )
+    }
+}
+
+impl fmt::Display for EmptyVectorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Vector or matrix is empty.")
+    }
+}
+
+impl fmt::Display for BadTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Value could not be converted to the required type.")
+    }
+}
+
+impl fmt::Display for NonUniformError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix rows are not all the same length.")
+    }
+}
+
+impl fmt::Display for NotImplementedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Operation is not implemented for this matrix shape.")
+    }
+}
+
+impl fmt::Display for SingularMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix is singular.")
+    }
+}
+
+impl fmt::Display for IndexOutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Index is out of bounds for this matrix.")
+    }
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "File could not be read or written.")
+    }
+}
+
+impl fmt::Display for NotFittedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Model has not been fit yet.")
+    }
+}
+
+impl fmt::Display for ZeroNormError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Vector has zero norm.")
+    }
+}
+
+impl fmt::Display for NotPositiveDefiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix is not positive definite.")
+    }
+}
+
+impl fmt::Display for NotConvergedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Iteration did not converge.")
+    }
+}
+
+impl fmt::Display for InvalidParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Parameter is outside of its valid range.")
+    }
 }
 
 impl fmt::Display for CustomErrors {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Matrix dimensions are mismatched.")
+        match self {
+            CustomErrors::EmptyVector(err) => err.fmt(f),
+            CustomErrors::Mismatch(err) => err.fmt(f),
+            CustomErrors::NonUniform(err) => err.fmt(f),
+            CustomErrors::BadType(err) => err.fmt(f),
+            CustomErrors::NotImplemented(err) => err.fmt(f),
+            CustomErrors::SingularMatrix(err) => err.fmt(f),
+            CustomErrors::IndexOutOfBounds(err) => err.fmt(f),
+            CustomErrors::Io(err) => err.fmt(f),
+            CustomErrors::NotFitted(err) => err.fmt(f),
+            CustomErrors::ZeroNorm(err) => err.fmt(f),
+            CustomErrors::NotPositiveDefinite(err) => err.fmt(f),
+            CustomErrors::NotConverged(err) => err.fmt(f),
+            CustomErrors::InvalidParameter(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for CustomErrors {}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BadTypeError, CustomErrors, EmptyVectorError, IndexOutOfBoundsError, InvalidParameterError,
+        IoError, MismatchError, NonUniformError, NotConvergedError, NotFittedError,
+        NotImplementedError, NotPositiveDefiniteError, SingularMatrixError, ZeroNormError,
+    };
+
+    #[test]
+    fn test_display() {
+        let err = CustomErrors::EmptyVector(EmptyVectorError);
+        assert!(err.to_string().contains("empty"));
+
+        let err = CustomErrors::Mismatch(MismatchError::new((2, 3), (3, 2)));
+        assert_eq!(err.to_string(), "shape mismatch: expected 2x3, found 3x2");
+
+        let err = CustomErrors::NonUniform(NonUniformError);
+        assert!(err.to_string().contains("same length"));
+
+        let err = CustomErrors::BadType(BadTypeError);
+        assert!(err.to_string().contains("converted"));
+
+        let err = CustomErrors::NotImplemented(NotImplementedError);
+        assert!(err.to_string().contains("not implemented"));
+
+        let err = CustomErrors::SingularMatrix(SingularMatrixError);
+        assert!(err.to_string().contains("singular"));
+
+        let err = CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError);
+        assert!(err.to_string().contains("out of bounds"));
+
+        let err = CustomErrors::Io(IoError);
+        assert!(err.to_string().contains("read or written"));
+
+        let err = CustomErrors::NotFitted(NotFittedError);
+        assert!(err.to_string().contains("not been fit"));
+
+        let err = CustomErrors::ZeroNorm(ZeroNormError);
+        assert!(err.to_string().contains("zero norm"));
+
+        let err = CustomErrors::NotPositiveDefinite(NotPositiveDefiniteError);
+        assert!(err.to_string().contains("positive definite"));
+
+        let err = CustomErrors::NotConverged(NotConvergedError);
+        assert!(err.to_string().contains("converge"));
+
+        let err = CustomErrors::InvalidParameter(InvalidParameterError);
+        assert!(err.to_string().contains("valid range"));
+    }
+
+    #[test]
+    fn test_boxed_error() {
+        fn fails() -> Result<(), Box<dyn std::error::Error>> {
+            Err(CustomErrors::Mismatch(MismatchError::new((1, 2), (2, 1))))?;
+            Ok(())
+        }
+
+        match fails() {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err.to_string(), "shape mismatch: expected 1x2, found 2x1"),
+        }
     }
 }
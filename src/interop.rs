@@ -0,0 +1,64 @@
+use crate::error::{CustomErrors, EmptyVectorError};
+use crate::matrix::Matrix;
+
+impl From<Matrix<f64>> for ndarray::Array2<f64> {
+    fn from(matrix: Matrix<f64>) -> ndarray::Array2<f64> {
+        // both layouts are row-major so the flat data moves across as is
+        ndarray::Array2::from_shape_vec((matrix.m, matrix.n), matrix.data)
+            .expect("matrix data length always matches its shape")
+    }
+}
+
+impl TryFrom<ndarray::Array2<f64>> for Matrix<f64> {
+    type Error = CustomErrors;
+
+    fn try_from(array: ndarray::Array2<f64>) -> Result<Matrix<f64>, CustomErrors> {
+        let (m, n) = array.dim();
+        if m == 0 || n == 0 {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        // iter walks in logical row-major order whatever the array's memory
+        // layout, so transposed or sliced views convert correctly too
+        let data: Vec<f64> = array.iter().cloned().collect();
+        Matrix::from_vec(data, m, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, EmptyVectorError};
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn test_array2_round_trip() {
+        let m = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+
+        let array: ndarray::Array2<f64> = m.clone().into();
+        assert_eq!(array.dim(), (3, 2));
+        assert_eq!(array[[2, 0]], 5.0);
+        assert_eq!(array, ndarray::array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let back = Matrix::try_from(array).unwrap();
+        assert_eq!(back, m);
+    }
+
+    #[test]
+    fn test_try_from_transposed_array() {
+        let array = ndarray::array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]].reversed_axes();
+        let m = Matrix::try_from(array).unwrap();
+        assert_eq!(
+            m,
+            Matrix::new(vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_try_from_empty_array() {
+        let array = ndarray::Array2::<f64>::zeros((0, 3));
+        match Matrix::try_from(array) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use crate::error::{BadTypeError, CustomErrors, EmptyVectorError, IoError};
+use crate::matrix::Matrix;
+
+pub fn from_csv(path: &str) -> Result<Matrix<f64>, CustomErrors> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(_) => return Err(CustomErrors::Io(IoError)),
+    };
+
+    let mut rows: Vec<Vec<f64>> = vec![];
+    for line in contents.lines() {
+        // tolerate blank lines, e.g. a trailing newline at the end of the file
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut row: Vec<f64> = vec![];
+        for field in line.split(',') {
+            let value: f64 = match field.trim().parse() {
+                Ok(value) => value,
+                Err(_) => return Err(CustomErrors::BadType(BadTypeError)),
+            };
+            row.push(value)
+        }
+        rows.push(row)
+    }
+
+    if rows.is_empty() {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    // ragged rows are rejected here with a NonUniformError
+    Matrix::new(rows)
+}
+
+pub fn to_csv(matrix: &Matrix<f64>, path: &str) -> Result<(), CustomErrors> {
+    let mut contents = String::new();
+    for row in matrix.iter_rows() {
+        let fields: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+        contents.push_str(&fields.join(","));
+        contents.push('\n');
+    }
+
+    match std::fs::write(path, contents) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(CustomErrors::Io(IoError)),
+    }
+}
+
+const NPY_MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+pub fn save_npy(matrix: &Matrix<f64>, path: &str) -> Result<(), CustomErrors> {
+    let mut header = format!(
+        "{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}",
+        matrix.m, matrix.n
+    );
+
+    // magic, version and header length take 10 bytes, the header is padded
+    // with spaces so the data starts on a 64 byte boundary
+    let preamble = NPY_MAGIC.len() + 4;
+    let total = (preamble + header.len() + 1).div_ceil(64) * 64;
+    while preamble + header.len() + 1 < total {
+        header.push(' ');
+    }
+    header.push('\n');
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(total + matrix.data.len() * 8);
+    bytes.extend_from_slice(NPY_MAGIC);
+    bytes.push(1);
+    bytes.push(0);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    for value in matrix.data.iter() {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    match std::fs::write(path, bytes) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(CustomErrors::Io(IoError)),
+    }
+}
+
+pub fn load_npy(path: &str) -> Result<Matrix<f64>, CustomErrors> {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(_) => return Err(CustomErrors::Io(IoError)),
+    };
+
+    // only version 1.0 headers are understood
+    let preamble = NPY_MAGIC.len() + 4;
+    if bytes.len() < preamble || &bytes[..6] != NPY_MAGIC || bytes[6] != 1 || bytes[7] != 0 {
+        return Err(CustomErrors::BadType(BadTypeError));
+    }
+
+    let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    if bytes.len() < preamble + header_len {
+        return Err(CustomErrors::BadType(BadTypeError));
+    }
+
+    let header = match std::str::from_utf8(&bytes[preamble..preamble + header_len]) {
+        Ok(header) => header,
+        Err(_) => return Err(CustomErrors::BadType(BadTypeError)),
+    };
+
+    let (m, n) = match parse_npy_header(header) {
+        Some(shape) => shape,
+        None => return Err(CustomErrors::BadType(BadTypeError)),
+    };
+
+    let body = &bytes[preamble + header_len..];
+    // the shape comes from the file, so guard against it overflowing
+    let expected_len = match m.checked_mul(n).and_then(|k| k.checked_mul(8)) {
+        Some(len) => len,
+        None => return Err(CustomErrors::BadType(BadTypeError)),
+    };
+    if body.len() != expected_len {
+        return Err(CustomErrors::BadType(BadTypeError));
+    }
+
+    let mut data: Vec<f64> = Vec::with_capacity(m * n);
+    for chunk in body.chunks_exact(8) {
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(chunk);
+        data.push(f64::from_le_bytes(raw));
+    }
+
+    Matrix::from_vec(data, m, n)
+}
+
+// returns the shape of a little endian f64, C ordered, 2D array header and
+// None for anything else
+fn parse_npy_header(header: &str) -> Option<(usize, usize)> {
+    // numpy writes python dict syntax, normalize spacing and quotes first
+    let header: String = header
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| if c == '"' { '\'' } else { c })
+        .collect();
+
+    if !header.contains("'descr':'<f8'") {
+        return None;
+    }
+
+    if !header.contains("'fortran_order':False") {
+        return None;
+    }
+
+    let start = header.find("'shape':(")? + "'shape':(".len();
+    let end = start + header[start..].find(')')?;
+
+    let mut dims: Vec<usize> = vec![];
+    for dim in header[start..end].split(',') {
+        if dim.is_empty() {
+            continue;
+        }
+        match dim.parse() {
+            Ok(dim) => dims.push(dim),
+            Err(_) => return None,
+        }
+    }
+
+    if dims.len() != 2 {
+        return None;
+    }
+
+    Some((dims[0], dims[1]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{BadTypeError, CustomErrors, EmptyVectorError, IoError, NonUniformError};
+    use crate::matrix::{logic::approx_eq, Matrix};
+
+    use super::{from_csv, load_npy, save_npy, to_csv};
+
+    fn temp_path(name: &str) -> String {
+        let mut path = std::env::temp_dir();
+        path.push(format!("pikus_{}_{}", std::process::id(), name));
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let path = temp_path("round_trip");
+        let m = Matrix::new(vec![
+            vec![1.0, -2.5, 3.25],
+            vec![0.1, 1e-7, 42.0],
+            vec![-0.0, 7.0, 1.0 / 3.0],
+        ])
+        .unwrap();
+
+        to_csv(&m, &path).unwrap();
+        let loaded = from_csv(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!((loaded.m, loaded.n), (3, 3));
+        assert!(approx_eq(&loaded, &m, 1e-12));
+    }
+
+    #[test]
+    fn test_from_csv_errors() {
+        let path = temp_path("ragged");
+        std::fs::write(&path, "1.0,2.0,3.0\n4.0,5.0\n").unwrap();
+        let ragged = from_csv(&path);
+        std::fs::remove_file(&path).unwrap();
+        match ragged {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NonUniform(NonUniformError)),
+        }
+
+        let path = temp_path("empty");
+        std::fs::write(&path, "").unwrap();
+        let empty = from_csv(&path);
+        std::fs::remove_file(&path).unwrap();
+        match empty {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+
+        match from_csv(&temp_path("missing")) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::Io(IoError)),
+        }
+    }
+
+    fn npy_bytes(header: &str, data: &[f64]) -> Vec<u8> {
+        // a version 1.0 file, header padded to 64 bytes
+        let mut header = header.to_string();
+        while !(10 + header.len() + 1).is_multiple_of(64) {
+            header.push(' ');
+        }
+        header.push('\n');
+
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for value in data {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_npy_round_trip() {
+        let path = temp_path("npy_round_trip");
+        let m = Matrix::new(vec![vec![1.0, -2.5, 3.25], vec![0.1, 1e-7, 42.0]]).unwrap();
+
+        save_npy(&m, &path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let loaded = load_npy(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // header is aligned so the data starts on a 64 byte boundary
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        assert_eq!((bytes.len() - 6 * 8) % 64, 0);
+
+        assert_eq!((loaded.m, loaded.n), (2, 3));
+        assert_eq!(loaded.data, m.data);
+    }
+
+    #[test]
+    fn test_load_npy_hand_built_header() {
+        // built by hand from the format description, not written by numpy,
+        // keys are reordered and quoted differently from what save_npy writes
+        let path = temp_path("npy_hand_built");
+        let header = "{\"shape\": (2, 3), \"fortran_order\": False, \"descr\": \"<f8\"}";
+        let bytes = npy_bytes(header, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(bytes.len(), 128 + 6 * 8);
+        std::fs::write(&path, bytes).unwrap();
+        let loaded = load_npy(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let expected = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert!(approx_eq(&loaded, &expected, 1e-12));
+    }
+
+    #[test]
+    fn test_load_npy_errors() {
+        let headers = [
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }",
+            "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (6,), }",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 2, 3), }",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 4), }",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (4611686018427387904, 4), }",
+        ];
+        for (i, header) in headers.iter().enumerate() {
+            let path = temp_path(&format!("npy_bad_{}", i));
+            std::fs::write(&path, npy_bytes(header, &[0.0; 6])).unwrap();
+            let loaded = load_npy(&path);
+            std::fs::remove_file(&path).unwrap();
+            match loaded {
+                Ok(_) => assert!(false),
+                Err(err) => assert_eq!(err, CustomErrors::BadType(BadTypeError)),
+            }
+        }
+
+        let path = temp_path("npy_not_numpy");
+        std::fs::write(&path, "1.0,2.0\n").unwrap();
+        let loaded = load_npy(&path);
+        std::fs::remove_file(&path).unwrap();
+        match loaded {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::BadType(BadTypeError)),
+        }
+
+        match load_npy(&temp_path("npy_missing")) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::Io(IoError)),
+        }
+    }
+}
@@ -1,5 +1,11 @@
 pub mod algebra;
+pub mod complex;
 pub mod error;
+#[cfg(feature = "ndarray")]
+pub mod interop;
+pub mod io;
 pub mod matrix;
 pub mod models;
+pub mod sparse;
+pub mod stats;
 pub mod vector;
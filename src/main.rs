@@ -1,9 +1,15 @@
 pub mod algebra;
+pub mod complex;
 pub mod data;
 pub mod error;
+#[cfg(feature = "ndarray")]
+pub mod interop;
+pub mod io;
 pub mod matrix;
 pub mod models;
 pub mod polynomial;
+pub mod sparse;
+pub mod stats;
 pub mod vector;
 
 fn main() {
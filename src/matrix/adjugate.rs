@@ -24,7 +24,10 @@ pub fn get_adjugate<
 
 #[cfg(test)]
 mod tests {
+    use crate::error::{CustomErrors, NotImplementedError};
+    use crate::matrix::operations::{get_determinant, multiply_matrices, scalar_multiply};
     use crate::matrix::Matrix;
+    use crate::vector::util::create_identity_matrix;
 
     use super::get_adjugate;
 
@@ -37,4 +40,33 @@ mod tests {
 
         assert_eq!(target_adjugate, calculated_adjugate)
     }
+
+    #[test]
+    fn test_get_adjugate_2_x_2() {
+        let matrix = Matrix::new(vec![vec![3, 7], vec![-2, 5]]).unwrap();
+        let target_adjugate = Matrix::new(vec![vec![5, -7], vec![2, 3]]).unwrap();
+
+        assert_eq!(get_adjugate(&matrix).unwrap(), target_adjugate)
+    }
+
+    #[test]
+    fn test_adjugate_identity() {
+        // A · adj(A) == det(A) · I
+        let matrix = Matrix::new(vec![vec![2, -1, 0], vec![1, 3, 4], vec![0, 5, -2]]).unwrap();
+        let adjugate = get_adjugate(&matrix).unwrap();
+        let determinant = get_determinant(&matrix).unwrap();
+
+        let product = multiply_matrices(&matrix, &adjugate).unwrap();
+        let identity: Matrix<i32> = create_identity_matrix(3).unwrap();
+        assert_eq!(product, scalar_multiply(&identity, determinant));
+    }
+
+    #[test]
+    fn test_get_adjugate_non_square() {
+        let matrix = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        match get_adjugate(&matrix) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+    }
 }
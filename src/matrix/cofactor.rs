@@ -1,6 +1,9 @@
-use crate::{error::CustomErrors, matrix::Matrix};
+use crate::{
+    error::{CustomErrors, IndexOutOfBoundsError, NotImplementedError},
+    matrix::Matrix,
+};
 
-use super::operations::get_determinant;
+use super::{logic::is_square, operations::get_determinant};
 
 pub fn get_minor<T: Copy + Into<f64> + std::convert::From<u8> + std::ops::AddAssign>(
     row_pos: usize,
@@ -11,9 +14,16 @@ pub fn get_minor<T: Copy + Into<f64> + std::convert::From<u8> + std::ops::AddAss
     // TODO implement dynamic solution for getting minors of a matrix?
     // or maybe some other solution besides O(n^2) for each element
 
+    if !is_square(matrix) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
     let n = matrix.n;
     let m = matrix.m;
-    let rows = &matrix.rows;
+
+    if row_pos >= m || col_pos >= n {
+        return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+    }
 
     let mut minor_rows: Vec<Vec<T>> = vec![];
 
@@ -27,7 +37,7 @@ pub fn get_minor<T: Copy + Into<f64> + std::convert::From<u8> + std::ops::AddAss
             if j == col_pos {
                 continue;
             }
-            minor_row.push(rows[i][j])
+            minor_row.push(matrix[(i, j)])
         }
         minor_rows.push(minor_row)
     }
@@ -39,6 +49,36 @@ pub fn get_minor<T: Copy + Into<f64> + std::convert::From<u8> + std::ops::AddAss
     }
 }
 
+// signed minor determinant, (-1)^(row + col) * det(minor)
+pub fn get_cofactor_element<
+    T: Copy
+        + std::ops::MulAssign
+        + std::fmt::Debug
+        + std::convert::From<u8>
+        + std::convert::From<i32>
+        + std::ops::Mul
+        + std::ops::Mul<Output = T>
+        + Into<f64>
+        + std::ops::AddAssign,
+>(
+    matrix: &Matrix<T>,
+    row_pos: usize,
+    col_pos: usize,
+) -> Result<T, CustomErrors> {
+    let element_minor = match get_minor(row_pos, col_pos, matrix) {
+        Ok(minor) => minor,
+        Err(err) => return Err(err),
+    };
+    let minor_det = match get_determinant(&element_minor) {
+        Ok(det) => det,
+        Err(err) => return Err(err),
+    };
+
+    let sign: i32 = if (row_pos + col_pos) % 2 == 0 { 1 } else { -1 };
+    let sign: T = sign.into();
+    Ok(sign * minor_det)
+}
+
 pub fn get_cofactor<
     T: Copy
         + std::ops::MulAssign
@@ -52,38 +92,24 @@ pub fn get_cofactor<
 >(
     matrix: &Matrix<T>,
 ) -> Result<Matrix<T>, CustomErrors> {
+    if !is_square(matrix) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
     let m = matrix.m;
     let n = matrix.n;
     let mut cofactor_rows: Vec<Vec<T>> = vec![];
-    let neg_one_cast: i32 = -1;
 
-    let mut row_count = 0;
     for i in 0..m {
         let mut cofactor_row: Vec<T> = vec![];
-        let mut col_count = 0;
         for j in 0..n {
-            let element_minor = match get_minor(i, j, &matrix) {
-                Ok(minor) => minor,
-                Err(err) => return Err(err),
-            };
-            let minor_det = match get_determinant(&element_minor) {
-                Ok(det) => det,
+            let cofactor = match get_cofactor_element(matrix, i, j) {
+                Ok(cofactor) => cofactor,
                 Err(err) => return Err(err),
             };
-
-            // since im zero indexing we need to translate into normal matrix
-            // nomeclature i.e. one indexing by adding two to our exp sum
-            // so 0 + 0 + 2 == 1 + 1 etc
-
-            let exp: u32 = row_count + col_count + 2;
-            let sign = neg_one_cast.pow(exp);
-            let sign: T = sign.into();
-            let cofactor = sign * minor_det;
             cofactor_row.push(cofactor);
-            col_count += 1
         }
         cofactor_rows.push(cofactor_row);
-        row_count += 1
     }
 
     match Matrix::new(cofactor_rows) {
@@ -94,10 +120,11 @@ pub fn get_cofactor<
 
 #[cfg(test)]
 mod tests {
+    use crate::error::{CustomErrors, IndexOutOfBoundsError, NotImplementedError};
     use crate::matrix::cofactor::get_minor;
     use crate::matrix::Matrix;
 
-    use super::get_cofactor;
+    use super::{get_cofactor, get_cofactor_element};
 
     #[test]
     fn test_get_minor_3_x_3() {
@@ -131,4 +158,31 @@ mod tests {
 
         assert_eq!(target_cofactor_matrix, calculated_cofactor_matrix);
     }
+
+    #[test]
+    fn test_get_cofactor_element() {
+        let matrix = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![5, 4, 3]]).unwrap();
+        assert_eq!(get_cofactor_element(&matrix, 0, 0).unwrap(), -9);
+        assert_eq!(get_cofactor_element(&matrix, 0, 1).unwrap(), 18);
+        assert_eq!(get_cofactor_element(&matrix, 2, 1).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_minor_errors() {
+        let non_square = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        match get_minor(0, 0, &non_square) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+        match get_cofactor(&non_square) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+
+        let matrix = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        match get_minor(2, 0, &matrix) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+    }
 }
@@ -0,0 +1,339 @@
+use crate::error::{
+    CustomErrors, NotImplementedError, NotPositiveDefiniteError, SingularMatrixError,
+};
+
+use super::{logic::is_square, Matrix};
+
+pub fn lu_decompose(
+    matrix: &Matrix<f64>,
+) -> Result<(Matrix<f64>, Matrix<f64>, Vec<usize>), CustomErrors> {
+    if !is_square(matrix) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
+    let n = matrix.n;
+    let mut upper = matrix.clone();
+    let mut lower = Matrix {
+        data: vec![0.0; n * n],
+        m: n,
+        n,
+    };
+    let mut permutation: Vec<usize> = (0..n).collect();
+
+    for k in 0..n {
+        // partial pivoting, pick the row with the largest magnitude
+        // element in column k
+        let mut pivot_row = k;
+        for i in k + 1..n {
+            if upper[(i, k)].abs() > upper[(pivot_row, k)].abs() {
+                pivot_row = i
+            }
+        }
+
+        if pivot_row != k {
+            for j in 0..n {
+                upper.data.swap(k * n + j, pivot_row * n + j)
+            }
+            permutation.swap(k, pivot_row);
+            // the multipliers already stored in L have to follow their rows
+            for j in 0..k {
+                lower.data.swap(k * n + j, pivot_row * n + j)
+            }
+        }
+
+        lower[(k, k)] = 1.0;
+
+        let pivot = upper[(k, k)];
+        if pivot == 0.0 {
+            // column is already zero below the diagonal, nothing to eliminate
+            continue;
+        }
+
+        for i in k + 1..n {
+            let factor = upper[(i, k)] / pivot;
+            lower[(i, k)] = factor;
+            for j in k..n {
+                upper.data[i * n + j] -= factor * upper.data[k * n + j]
+            }
+        }
+    }
+
+    Ok((lower, upper, permutation))
+}
+
+// only the lower triangle of the (assumed symmetric) input is read
+pub fn cholesky(matrix: &Matrix<f64>) -> Result<Matrix<f64>, CustomErrors> {
+    if !is_square(matrix) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
+    let n = matrix.n;
+    let mut lower = Matrix {
+        data: vec![0.0; n * n],
+        m: n,
+        n,
+    };
+
+    for j in 0..n {
+        let mut diagonal = matrix[(j, j)];
+        for k in 0..j {
+            diagonal -= lower[(j, k)] * lower[(j, k)]
+        }
+
+        if diagonal <= 0.0 {
+            return Err(CustomErrors::NotPositiveDefinite(NotPositiveDefiniteError));
+        }
+        let diagonal = diagonal.sqrt();
+        lower[(j, j)] = diagonal;
+
+        for i in j + 1..n {
+            let mut sum = matrix[(i, j)];
+            for k in 0..j {
+                sum -= lower[(i, k)] * lower[(j, k)]
+            }
+            lower[(i, j)] = sum / diagonal
+        }
+    }
+
+    Ok(lower)
+}
+
+// reduced QR, Q is m x n with orthonormal columns and R is n x n
+pub fn qr_decompose(matrix: &Matrix<f64>) -> Result<(Matrix<f64>, Matrix<f64>), CustomErrors> {
+    let m = matrix.m;
+    let n = matrix.n;
+    if m < n {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
+    // a column counts as dependent when little of it is left after removing
+    // the earlier directions, measured against the column's original length
+    let tolerance = 1e-12;
+    // modified Gram-Schmidt, each new q is removed from all of the remaining
+    // columns straight away rather than projecting the original columns,
+    // which loses far less orthogonality to rounding
+    let mut columns: Vec<Vec<f64>> = vec![];
+    for j in 0..n {
+        match matrix.col(j) {
+            Ok(col) => columns.push(col),
+            Err(err) => return Err(err),
+        }
+    }
+
+    let mut original_norms: Vec<f64> = vec![];
+    for j in 0..n {
+        let mut norm = 0.0;
+        for i in 0..m {
+            norm += columns[j][i] * columns[j][i]
+        }
+        original_norms.push(norm.sqrt())
+    }
+
+    let mut q = Matrix {
+        data: vec![0.0; m * n],
+        m,
+        n,
+    };
+    let mut r = Matrix {
+        data: vec![0.0; n * n],
+        m: n,
+        n,
+    };
+
+    for j in 0..n {
+        let mut norm = 0.0;
+        for i in 0..m {
+            norm += columns[j][i] * columns[j][i]
+        }
+        let norm = norm.sqrt();
+
+        // linearly dependent columns leave nothing to normalize
+        if norm <= tolerance * original_norms[j] {
+            return Err(CustomErrors::SingularMatrix(SingularMatrixError));
+        }
+
+        r[(j, j)] = norm;
+        for i in 0..m {
+            q[(i, j)] = columns[j][i] / norm
+        }
+
+        for k in j + 1..n {
+            let mut projection = 0.0;
+            for i in 0..m {
+                projection += q[(i, j)] * columns[k][i]
+            }
+            r[(j, k)] = projection;
+            for i in 0..m {
+                columns[k][i] -= projection * q[(i, j)]
+            }
+        }
+    }
+
+    Ok((q, r))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{
+        CustomErrors, NotImplementedError, NotPositiveDefiniteError, SingularMatrixError,
+    };
+    use crate::matrix::logic::approx_eq;
+    use crate::matrix::operations::multiply_matrices;
+    use crate::matrix::Matrix;
+    use crate::vector::util::create_identity_matrix;
+
+    use super::{cholesky, lu_decompose, qr_decompose};
+
+    fn check_lu(rows: Vec<Vec<f64>>) {
+        let matrix = Matrix::new(rows).unwrap();
+        let (l, u, p) = lu_decompose(&matrix).unwrap();
+        let n = matrix.n;
+
+        let mut permuted_rows: Vec<Vec<f64>> = vec![];
+        for i in 0..n {
+            permuted_rows.push(matrix.row(p[i]).unwrap())
+        }
+        let lu = multiply_matrices(&l, &u).unwrap();
+
+        for i in 0..n {
+            assert_eq!(l[(i, i)], 1.0);
+            for j in 0..n {
+                if j > i {
+                    assert_eq!(l[(i, j)], 0.0);
+                }
+                if j < i {
+                    assert!(u[(i, j)].abs() < 1e-12);
+                }
+                assert!((lu[(i, j)] - permuted_rows[i][j]).abs() < 1e-9)
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_decompose() {
+        check_lu(vec![
+            vec![2.0, 1.0, 1.0],
+            vec![4.0, -6.0, 0.0],
+            vec![-2.0, 7.0, 2.0],
+        ]);
+
+        check_lu(vec![
+            vec![0.0, 2.0, 1.0, 4.0],
+            vec![1.0, 1.0, 0.0, 3.0],
+            vec![3.0, -1.0, 2.0, 1.0],
+            vec![2.0, 0.0, 5.0, -2.0],
+        ]);
+    }
+
+    #[test]
+    fn test_lu_decompose_non_square() {
+        let matrix = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        match lu_decompose(&matrix) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+    }
+
+    fn check_qr(rows: Vec<Vec<f64>>) {
+        let matrix = Matrix::new(rows).unwrap();
+        let (q, r) = qr_decompose(&matrix).unwrap();
+        assert_eq!((q.m, q.n), (matrix.m, matrix.n));
+        assert_eq!((r.m, r.n), (matrix.n, matrix.n));
+
+        let qr = multiply_matrices(&q, &r).unwrap();
+        assert!(approx_eq(&qr, &matrix, 1e-9));
+
+        let qtq = multiply_matrices(&q.transpose(), &q).unwrap();
+        let identity = create_identity_matrix(matrix.n).unwrap();
+        assert!(approx_eq(&qtq, &identity, 1e-9));
+
+        for i in 0..r.m {
+            for j in 0..i {
+                assert_eq!(r[(i, j)], 0.0)
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_decompose() {
+        check_qr(vec![
+            vec![12.0, -51.0, 4.0],
+            vec![6.0, 167.0, -68.0],
+            vec![-4.0, 24.0, -41.0],
+        ]);
+
+        check_qr(vec![
+            vec![1.0, 2.0],
+            vec![3.0, 4.0],
+            vec![5.0, 6.0],
+            vec![7.0, 9.0],
+        ]);
+
+        // full rank, just small
+        let small: Matrix<f64> = create_identity_matrix(4).unwrap();
+        check_qr(small.map(|v| v * 1e-13).rows());
+    }
+
+    #[test]
+    fn test_qr_decompose_errors() {
+        let wide = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        match qr_decompose(&wide) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+
+        let dependent = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]).unwrap();
+        match qr_decompose(&dependent) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+
+        // the second column is a tenth of the first, only rounding is left over
+        let dependent = Matrix::new(vec![vec![1e6, 1e5], vec![3e6, 3e5], vec![7e6, 7e5]]).unwrap();
+        match qr_decompose(&dependent) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let matrix = Matrix::new(vec![
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ])
+        .unwrap();
+        let target = Matrix::new(vec![
+            vec![2.0, 0.0, 0.0],
+            vec![6.0, 1.0, 0.0],
+            vec![-8.0, 5.0, 3.0],
+        ])
+        .unwrap();
+
+        let lower = cholesky(&matrix).unwrap();
+        assert!(approx_eq(&lower, &target, 1e-12));
+
+        let llt = multiply_matrices(&lower, &lower.transpose()).unwrap();
+        assert!(approx_eq(&llt, &matrix, 1e-9));
+    }
+
+    #[test]
+    fn test_cholesky_errors() {
+        // symmetric with eigenvalues 3 and -1
+        let indefinite = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+        match cholesky(&indefinite) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::NotPositiveDefinite(NotPositiveDefiniteError)
+            ),
+        }
+
+        let non_square = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        match cholesky(&non_square) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use super::{logic::is_square, Matrix};
+use crate::error::{CustomErrors, NotConvergedError, NotImplementedError};
+use crate::vector::operations::{dot, normalize};
+
+use super::{logic::is_square, operations::multiply_matrix_vector, Matrix};
 
 pub struct EigenMatrix {
     pub rows: Vec<Vec<String>>,
@@ -16,13 +19,138 @@ pub fn get_expanded_eigen_polynomial<
     }
 
     let m = matrix.m;
-    let rows = &matrix.rows;
     let mut terms: Vec<String> = vec![];
     for i in 0..m {
-        let diag_element = rows[i][i];
+        let diag_element = matrix[(i, i)];
         let diag_str = diag_element.to_string();
         terms.push(format!("({}-x)", diag_str))
     }
 
     terms
 }
+
+pub fn power_iteration(
+    matrix: &Matrix<f64>,
+    max_iters: usize,
+    tolerance: f64,
+) -> Result<(f64, Vec<f64>), CustomErrors> {
+    if !is_square(matrix) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
+    // start from 1, 2, ..., n rather than all ones, an all ones start is
+    // orthogonal to common dominant eigenvectors such as [1, -1]
+    let n = matrix.n;
+    let scale = matrix.data.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+    let start: Vec<f64> = (1..=n).map(|i| i as f64).collect();
+    let mut vector = match normalize(&start) {
+        Ok(vector) => vector,
+        Err(err) => return Err(err),
+    };
+
+    for _ in 0..max_iters {
+        let product = match multiply_matrix_vector(matrix, &vector) {
+            Ok(product) => product,
+            Err(err) => return Err(err),
+        };
+
+        // the Rayleigh quotient vᵀAv of the current unit vector, this keeps
+        // the sign of a negative dominant eigenvalue
+        let eigenvalue = match dot(&vector, &product) {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        };
+
+        // converged once ‖Av - λv‖ is small next to the largest entry of the
+        // matrix, a steady λ alone is not enough since the iterate can flip
+        // between two vectors when λ and -λ are both dominant
+        let mut residual = 0.0;
+        for i in 0..n {
+            residual += (product[i] - eigenvalue * vector[i]).powi(2);
+        }
+        if residual.sqrt() <= tolerance * scale {
+            return Ok((eigenvalue, vector));
+        }
+
+        vector = match normalize(&product) {
+            Ok(vector) => vector,
+            Err(err) => return Err(err),
+        };
+    }
+
+    Err(CustomErrors::NotConverged(NotConvergedError))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, NotConvergedError, NotImplementedError};
+    use crate::matrix::operations::multiply_matrix_vector;
+    use crate::matrix::Matrix;
+
+    use super::power_iteration;
+
+    #[test]
+    fn test_power_iteration() {
+        // eigenvalues (5 ± √5) / 2
+        let matrix = Matrix::new(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        let (eigenvalue, eigenvector) = power_iteration(&matrix, 1000, 1e-12).unwrap();
+
+        let target = (5.0 + 5.0_f64.sqrt()) / 2.0;
+        assert!((eigenvalue - target).abs() < 1e-9);
+
+        // Av = λv
+        let product = multiply_matrix_vector(&matrix, &eigenvector).unwrap();
+        for i in 0..2 {
+            assert!((product[i] - eigenvalue * eigenvector[i]).abs() < 1e-6)
+        }
+    }
+
+    #[test]
+    fn test_power_iteration_orthogonal_to_ones() {
+        // eigenvalues 3 along [1, -1] and -1 along [1, 1], the dominant
+        // eigenvector is orthogonal to an all ones start
+        let matrix = Matrix::new(vec![vec![1.0, -2.0], vec![-2.0, 1.0]]).unwrap();
+        let (eigenvalue, eigenvector) = power_iteration(&matrix, 1000, 1e-12).unwrap();
+
+        assert!((eigenvalue - 3.0).abs() < 1e-9);
+        assert!((eigenvector[0] + eigenvector[1]).abs() < 1e-9);
+
+        let product = multiply_matrix_vector(&matrix, &eigenvector).unwrap();
+        for i in 0..2 {
+            assert!((product[i] - eigenvalue * eigenvector[i]).abs() < 1e-9)
+        }
+    }
+
+    #[test]
+    fn test_power_iteration_small_entries() {
+        // same eigenpairs as above scaled by 1e-13, every entry is below the
+        // tolerance so an absolute residual check would accept the start vector
+        let matrix = Matrix::new(vec![vec![1e-13, -2e-13], vec![-2e-13, 1e-13]]).unwrap();
+        let (eigenvalue, eigenvector) = power_iteration(&matrix, 1000, 1e-12).unwrap();
+
+        assert!((eigenvalue - 3e-13).abs() < 1e-21);
+        assert!((eigenvector[0] + eigenvector[1]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_power_iteration_errors() {
+        let non_square = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        match power_iteration(&non_square, 100, 1e-9) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+
+        let matrix = Matrix::new(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        match power_iteration(&matrix, 2, 1e-12) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotConverged(NotConvergedError)),
+        }
+
+        // 1 and -1 tie in magnitude, the iterate never settles
+        let matrix = Matrix::new(vec![vec![1.0, 0.0], vec![0.0, -1.0]]).unwrap();
+        match power_iteration(&matrix, 1000, 1e-9) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotConverged(NotConvergedError)),
+        }
+    }
+}
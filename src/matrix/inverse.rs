@@ -1,8 +1,9 @@
-use crate::error::{CustomErrors, SingularMatrixError};
+use crate::error::{CustomErrors, NotImplementedError, SingularMatrixError};
 
 use super::{
     adjugate::get_adjugate,
-    operations::{get_determinant, scalar_divide},
+    logic::is_square,
+    operations::{get_determinant, multiply_matrices, scalar_divide},
     Matrix,
 };
 
@@ -44,15 +45,114 @@ pub fn cramer_inverse<
     }
 }
 
-fn _gaussian_inverse() {
-    println!("not implemented!")
+pub fn inverse(matrix: &Matrix<f64>) -> Result<Matrix<f64>, CustomErrors> {
+    if !is_square(matrix) {
+        return Err(CustomErrors::NotImplemented(NotImplementedError));
+    }
+
+    // pivots are compared against the largest entry rather than an absolute
+    // cutoff, so a well-conditioned matrix with small entries is not singular
+    let n = matrix.n;
+    let scale = matrix.data.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+    let tolerance = 1e-12 * scale;
+
+    // augment the matrix with an identity matrix i.e. [A | I]
+    let mut augmented_rows: Vec<Vec<f64>> = vec![];
+    for i in 0..n {
+        let mut augmented_row = matrix.data[i * n..(i + 1) * n].to_vec();
+        for j in 0..n {
+            if i == j {
+                augmented_row.push(1.0)
+            } else {
+                augmented_row.push(0.0)
+            }
+        }
+        augmented_rows.push(augmented_row)
+    }
+
+    for col in 0..n {
+        // partial pivoting, bring the row with the largest element
+        // in this column up to the pivot position
+        let mut pivot_row = col;
+        for i in col + 1..n {
+            if augmented_rows[i][col].abs() > augmented_rows[pivot_row][col].abs() {
+                pivot_row = i
+            }
+        }
+
+        if augmented_rows[pivot_row][col].abs() <= tolerance {
+            return Err(CustomErrors::SingularMatrix(SingularMatrixError));
+        }
+
+        augmented_rows.swap(col, pivot_row);
+
+        let pivot = augmented_rows[col][col];
+        for j in 0..2 * n {
+            augmented_rows[col][j] /= pivot
+        }
+
+        for i in 0..n {
+            if i == col {
+                continue;
+            }
+            let factor = augmented_rows[i][col];
+            if factor == 0.0 {
+                continue;
+            }
+            for j in 0..2 * n {
+                augmented_rows[i][j] -= factor * augmented_rows[col][j]
+            }
+        }
+    }
+
+    // right half of [I | A^-1] is the inverse
+    let mut inverse_rows: Vec<Vec<f64>> = vec![];
+    for i in 0..n {
+        inverse_rows.push(augmented_rows[i][n..].to_vec())
+    }
+
+    Matrix::new(inverse_rows)
+}
+
+// Moore-Penrose pseudo-inverse of a full rank matrix. tall matrices use the
+// left inverse (AᵀA)⁻¹Aᵀ and wide ones the right inverse Aᵀ(AAᵀ)⁻¹, whichever
+// Gram matrix is the smaller square. rank deficient input leaves that Gram
+// matrix singular and errors
+pub fn pseudo_inverse(matrix: &Matrix<f64>) -> Result<Matrix<f64>, CustomErrors> {
+    let transposed = matrix.transpose();
+
+    if matrix.m >= matrix.n {
+        let gram = match multiply_matrices(&transposed, matrix) {
+            Ok(gram) => gram,
+            Err(err) => return Err(err),
+        };
+        let gram_inverse = match inverse(&gram) {
+            Ok(gram_inverse) => gram_inverse,
+            Err(err) => return Err(err),
+        };
+        multiply_matrices(&gram_inverse, &transposed)
+    } else {
+        let gram = match multiply_matrices(matrix, &transposed) {
+            Ok(gram) => gram,
+            Err(err) => return Err(err),
+        };
+        let gram_inverse = match inverse(&gram) {
+            Ok(gram_inverse) => gram_inverse,
+            Err(err) => return Err(err),
+        };
+        multiply_matrices(&transposed, &gram_inverse)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::error::{CustomErrors, NotImplementedError, SingularMatrixError};
+    use crate::matrix::logic::approx_eq;
+    use crate::matrix::operations::multiply_matrices;
     use crate::matrix::Matrix;
+    use crate::vector::util::create_identity_matrix;
 
-    use super::cramer_inverse;
+    use super::{cramer_inverse, inverse, pseudo_inverse};
 
     #[test]
     fn test_cramer_inverse() {
@@ -69,4 +169,116 @@ mod tests {
 
         assert_eq!(target_inverse, calculated_inverse)
     }
+
+    #[test]
+    fn test_inverse_1_x_1() {
+        let m = Matrix::new(vec![vec![4.0]]).unwrap();
+        let target_inverse = Matrix::new(vec![vec![0.25]]).unwrap();
+        let calculated_inverse = inverse(&m).unwrap();
+
+        assert_eq!(target_inverse, calculated_inverse)
+    }
+
+    #[test]
+    fn test_inverse_3_x_3() {
+        let rows = vec![
+            vec![2.0, 0.0, 0.0],
+            vec![0.0, 4.0, 0.0],
+            vec![1.0, 0.0, 1.0],
+        ];
+        let m = Matrix::new(rows).unwrap();
+        let target_rows = vec![
+            vec![0.5, 0.0, 0.0],
+            vec![0.0, 0.25, 0.0],
+            vec![-0.5, 0.0, 1.0],
+        ];
+        let calculated_inverse = inverse(&m).unwrap();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((calculated_inverse[(i, j)] - target_rows[i][j]).abs() < 1e-9)
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_product_is_identity() {
+        let rows = vec![
+            vec![1.0, 1.0, 1.0],
+            vec![3.0, 2.0, 1.0],
+            vec![2.0, 1.0, 3.0],
+        ];
+        let m = Matrix::new(rows).unwrap();
+        let calculated_inverse = inverse(&m).unwrap();
+        let product = multiply_matrices(&m, &calculated_inverse).unwrap();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product[(i, j)] - expected).abs() < 1e-9)
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_small_entries() {
+        let identity: Matrix<f64> = create_identity_matrix(8).unwrap();
+        let small = identity.map(|v| v * 1e-13);
+        let calculated_inverse = inverse(&small).unwrap();
+        let target = identity.map(|v| v * 1e13);
+        assert!(approx_eq(&calculated_inverse, &target, 1e-3));
+
+        let zero = Matrix::new(vec![vec![0.0, 0.0], vec![0.0, 0.0]]).unwrap();
+        match inverse(&zero) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+    }
+
+    #[test]
+    fn test_inverse_errors() {
+        let singular = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        match inverse(&singular) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+
+        let non_square = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 5.0]]).unwrap();
+        match inverse(&non_square) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+    }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let tall = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 7.0]]).unwrap();
+        let pinv = pseudo_inverse(&tall).unwrap();
+        assert_eq!((pinv.m, pinv.n), (2, 3));
+
+        let product = multiply_matrices(&pinv, &tall).unwrap();
+        let identity: Matrix<f64> = create_identity_matrix(2).unwrap();
+        assert!(approx_eq(&product, &identity, 1e-9));
+
+        // wide matrices get a right inverse instead
+        let wide = tall.transpose();
+        let pinv = pseudo_inverse(&wide).unwrap();
+        let product = multiply_matrices(&wide, &pinv).unwrap();
+        assert!(approx_eq(&product, &identity, 1e-9));
+
+        // a square invertible matrix gives back its inverse
+        let square = Matrix::new(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        assert!(approx_eq(
+            &pseudo_inverse(&square).unwrap(),
+            &inverse(&square).unwrap(),
+            1e-9
+        ));
+
+        let rank_deficient =
+            Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]).unwrap();
+        match pseudo_inverse(&rank_deficient) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::SingularMatrix(SingularMatrixError)),
+        }
+    }
 }
@@ -1,9 +1,12 @@
 use std::collections::HashMap;
 
+use crate::matrix::decomposition::{cholesky, lu_decompose};
+use crate::matrix::operations::multiply_matrices;
 use crate::matrix::Matrix;
+use crate::vector::util::create_identity_matrix;
 
 pub fn can_add<T: Copy>(matrix_1: &Matrix<T>, matrix_2: &Matrix<T>) -> bool {
-    if matrix_1.m != matrix_2.n {
+    if matrix_1.m != matrix_2.m {
         return false;
     }
 
@@ -28,10 +31,99 @@ pub fn is_square<T: Copy>(matrix: &Matrix<T>) -> bool {
     true
 }
 
+pub fn is_symmetric<T: Copy + PartialEq>(matrix: &Matrix<T>) -> bool {
+    if !is_square(matrix) {
+        return false;
+    }
+
+    for i in 0..matrix.m {
+        for j in i + 1..matrix.n {
+            if matrix[(i, j)] != matrix[(j, i)] {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+pub fn is_diagonal<T: Copy + PartialEq + From<u8>>(matrix: &Matrix<T>) -> bool {
+    let zero_cast: T = 0.into();
+
+    for i in 0..matrix.m {
+        for j in 0..matrix.n {
+            if i != j && matrix[(i, j)] != zero_cast {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+pub fn approx_eq(matrix_1: &Matrix<f64>, matrix_2: &Matrix<f64>, tolerance: f64) -> bool {
+    if matrix_1.m != matrix_2.m || matrix_1.n != matrix_2.n {
+        return false;
+    }
+
+    for i in 0..matrix_1.m {
+        for j in 0..matrix_1.n {
+            // written so that a NaN difference counts as unequal
+            if !((matrix_1[(i, j)] - matrix_2[(i, j)]).abs() <= tolerance) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+pub fn is_orthogonal(matrix: &Matrix<f64>, tolerance: f64) -> bool {
+    if !is_square(matrix) {
+        return false;
+    }
+
+    let gram = match multiply_matrices(&matrix.transpose(), matrix) {
+        Ok(gram) => gram,
+        Err(_) => return false,
+    };
+    let identity = match create_identity_matrix(matrix.n) {
+        Ok(identity) => identity,
+        Err(_) => return false,
+    };
+
+    approx_eq(&gram, &identity, tolerance)
+}
+
+// symmetric and the Cholesky factorization goes through
+pub fn is_positive_definite(matrix: &Matrix<f64>) -> bool {
+    if !is_symmetric(matrix) {
+        return false;
+    }
+
+    cholesky(matrix).is_ok()
+}
+
+// a pivot of U below tolerance means there is no inverse, which is more
+// trustworthy than checking a floating point determinant against zero.
+// non-square matrices have no inverse either, so they count as singular
+pub fn is_singular(matrix: &Matrix<f64>, tolerance: f64) -> bool {
+    let (_, upper, _) = match lu_decompose(matrix) {
+        Ok(lu) => lu,
+        Err(_) => return true,
+    };
+
+    for i in 0..upper.n {
+        if upper[(i, i)].abs() < tolerance {
+            return true;
+        }
+    }
+    false
+}
+
 pub fn is_tridiagonal<T: Copy + From<u8> + PartialEq>(matrix: &Matrix<T>) -> bool {
     let m = matrix.m;
     let n = matrix.n;
-    let rows = &matrix.rows;
     let zero_cast: T = 0.into();
 
     let mut valid_hash_map: HashMap<i32, usize> = HashMap::new();
@@ -49,7 +141,7 @@ pub fn is_tridiagonal<T: Copy + From<u8> + PartialEq>(matrix: &Matrix<T>) -> boo
         if valid_hash_map.contains_key(&a) {
             let a_u = valid_hash_map.get(&a).unwrap();
 
-            if rows[i][*a_u] == zero_cast {
+            if matrix[(i, *a_u)] == zero_cast {
                 return false;
             }
         }
@@ -57,7 +149,7 @@ pub fn is_tridiagonal<T: Copy + From<u8> + PartialEq>(matrix: &Matrix<T>) -> boo
         if valid_hash_map.contains_key(&b) {
             let b_u = valid_hash_map.get(&b).unwrap();
 
-            if rows[i][*b_u] == zero_cast {
+            if matrix[(i, *b_u)] == zero_cast {
                 return false;
             }
         }
@@ -65,7 +157,7 @@ pub fn is_tridiagonal<T: Copy + From<u8> + PartialEq>(matrix: &Matrix<T>) -> boo
         if valid_hash_map.contains_key(&c) {
             let c_u = valid_hash_map.get(&c).unwrap();
 
-            if rows[i][*c_u] == zero_cast {
+            if matrix[(i, *c_u)] == zero_cast {
                 return false;
             }
         }
@@ -76,3 +168,161 @@ pub fn is_tridiagonal<T: Copy + From<u8> + PartialEq>(matrix: &Matrix<T>) -> boo
 
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    use super::{
+        approx_eq, can_add, is_diagonal, is_orthogonal, is_positive_definite, is_singular,
+        is_symmetric,
+    };
+
+    #[test]
+    fn test_can_add() {
+        let m1 = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let m2 = Matrix::new(vec![vec![6, 5, 4], vec![3, 2, 1]]).unwrap();
+        assert_eq!(can_add(&m1, &m2), true);
+
+        // mismatched row counts
+        let m3 = Matrix::new(vec![vec![1, 2, 3]]).unwrap();
+        assert_eq!(can_add(&m1, &m3), false);
+
+        // mismatched column counts
+        let m4 = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(can_add(&m1, &m4), false);
+
+        // same number of elements but transposed shape
+        let m5 = m1.transpose();
+        assert_eq!(can_add(&m1, &m5), false);
+        assert_eq!(can_add(&m5, &m1), false);
+    }
+
+    #[test]
+    fn test_matrix_equality() {
+        let m1 = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let m2 = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let m3 = Matrix::new(vec![vec![1, 2], vec![3, 5]]).unwrap();
+        let m4 = Matrix::new(vec![vec![1, 2, 3, 4]]).unwrap();
+
+        assert_eq!(m1 == m2, true);
+        assert_eq!(m1 == m3, false);
+        assert_eq!(m1 == m4, false);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let m1 = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let m2 = Matrix::new(vec![vec![1.0 + 1e-12, 2.0], vec![3.0, 4.0]]).unwrap();
+        let m3 = Matrix::new(vec![vec![1.1, 2.0], vec![3.0, 4.0]]).unwrap();
+        let m4 = Matrix::new(vec![vec![1.0, 2.0]]).unwrap();
+
+        assert_eq!(m1 == m2, false);
+        assert_eq!(approx_eq(&m1, &m2, 1e-9), true);
+        assert_eq!(approx_eq(&m1, &m3, 1e-9), false);
+        assert_eq!(approx_eq(&m1, &m4, 1e-9), false);
+
+        let nan = Matrix::new(vec![vec![f64::NAN]]).unwrap();
+        let one = Matrix::new(vec![vec![1.0]]).unwrap();
+        assert_eq!(approx_eq(&nan, &one, 1e-9), false);
+        assert_eq!(approx_eq(&nan, &nan, 1e-9), false);
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        let symmetric = Matrix::new(vec![vec![1, 2, 3], vec![2, 4, 5], vec![3, 5, 6]]).unwrap();
+        let non_symmetric = Matrix::new(vec![vec![1, 2, 3], vec![2, 4, 5], vec![0, 5, 6]]).unwrap();
+        let rectangular = Matrix::new(vec![vec![1, 2, 3], vec![2, 4, 5]]).unwrap();
+
+        assert_eq!(is_symmetric(&symmetric), true);
+        assert_eq!(is_symmetric(&non_symmetric), false);
+        assert_eq!(is_symmetric(&rectangular), false);
+    }
+
+    #[test]
+    fn test_is_diagonal() {
+        let diagonal = Matrix::new(vec![vec![1.0, 0.0], vec![0.0, -3.0]]).unwrap();
+        let non_diagonal = Matrix::new(vec![vec![1.0, 0.5], vec![0.0, -3.0]]).unwrap();
+        let rectangular = Matrix::new(vec![vec![1, 0, 0], vec![0, 2, 0]]).unwrap();
+        let rectangular_with_values = Matrix::new(vec![vec![1, 0, 4], vec![0, 2, 0]]).unwrap();
+
+        assert_eq!(is_diagonal(&diagonal), true);
+        assert_eq!(is_diagonal(&non_diagonal), false);
+        assert_eq!(is_diagonal(&rectangular), true);
+        assert_eq!(is_diagonal(&rectangular_with_values), false);
+    }
+
+    #[test]
+    fn test_is_orthogonal() {
+        let theta: f64 = 0.3;
+        let rotation = Matrix::new(vec![
+            vec![theta.cos(), -theta.sin()],
+            vec![theta.sin(), theta.cos()],
+        ])
+        .unwrap();
+        assert!(is_orthogonal(&rotation, 1e-12));
+
+        let permutation = Matrix::new(vec![
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![1.0, 0.0, 0.0],
+        ])
+        .unwrap();
+        assert!(is_orthogonal(&permutation, 1e-12));
+
+        let shear = Matrix::new(vec![vec![1.0, 1.0], vec![0.0, 1.0]]).unwrap();
+        assert!(!is_orthogonal(&shear, 1e-12));
+
+        let non_square = Matrix::new(vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]]).unwrap();
+        assert!(!is_orthogonal(&non_square, 1e-12));
+    }
+
+    #[test]
+    fn test_is_positive_definite() {
+        let spd = Matrix::new(vec![
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ])
+        .unwrap();
+        assert!(is_positive_definite(&spd));
+
+        let indefinite = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+        assert!(!is_positive_definite(&indefinite));
+
+        let non_symmetric = Matrix::new(vec![vec![2.0, 1.0], vec![0.0, 2.0]]).unwrap();
+        assert!(!is_positive_definite(&non_symmetric));
+    }
+
+    #[test]
+    fn test_is_singular() {
+        // the third row is the sum of the first two
+        let singular = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![5.0, 7.0, 9.0],
+        ])
+        .unwrap();
+        assert!(is_singular(&singular, 1e-10));
+
+        // rounding leaves a tiny pivot that an exact zero check would miss
+        let nearly = Matrix::new(vec![
+            vec![0.1, 0.2, 0.3],
+            vec![0.4, 0.5, 0.6],
+            vec![0.7, 0.8, 0.9],
+        ])
+        .unwrap();
+        assert!(is_singular(&nearly, 1e-10));
+
+        let well_conditioned = Matrix::new(vec![
+            vec![4.0, 1.0, 0.0],
+            vec![1.0, 3.0, 1.0],
+            vec![0.0, 1.0, 2.0],
+        ])
+        .unwrap();
+        assert!(!is_singular(&well_conditioned, 1e-10));
+
+        let rect = Matrix::new(vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]]).unwrap();
+        assert!(is_singular(&rect, 1e-10));
+    }
+}
@@ -1,21 +1,48 @@
 pub mod adjugate;
 pub mod cofactor;
+pub mod decomposition;
 pub mod eigen;
 pub mod inverse;
 pub mod logic;
 pub mod operations;
 pub mod transform;
-use crate::error::{CustomErrors, EmptyVectorError, NonUniformError};
+use crate::error::{
+    CustomErrors, EmptyVectorError, IndexOutOfBoundsError, InvalidParameterError, MismatchError,
+    NonUniformError, NotImplementedError,
+};
 use crate::vector::operations::{mean, stddev};
 
+// builds a matrix from semicolon separated rows, e.g. `matrix![1, 2; 3, 4]`.
+// ragged rows panic with the NonUniform error returned by `Matrix::new`
+#[macro_export]
+macro_rules! matrix {
+    ($($($x:expr),+);+ $(;)?) => {
+        $crate::matrix::Matrix::new(vec![$(vec![$($x),+]),+]).unwrap()
+    };
+}
+
+// elements are stored in a single row-major vector, so (i, j) lives at
+// data[i * n + j]. this keeps whole rows contiguous in memory
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "SerializedMatrix<T>",
+        try_from = "SerializedMatrix<T>",
+        bound(
+            serialize = "T: serde::Serialize",
+            deserialize = "T: serde::Deserialize<'de>"
+        )
+    )
+)]
 pub struct Matrix<T: Copy> {
-    pub rows: Vec<Vec<T>>,
+    pub data: Vec<T>,
     pub m: usize,
     pub n: usize,
 }
 
-impl<T: Copy + From<u8> + Into<f64> + std::ops::AddAssign> Matrix<T> {
+impl<T: Copy> Matrix<T> {
     pub fn new(rows: Vec<Vec<T>>) -> Result<Matrix<T>, CustomErrors> {
         let m = rows.len();
 
@@ -34,57 +61,463 @@ impl<T: Copy + From<u8> + Into<f64> + std::ops::AddAssign> Matrix<T> {
                 return Err(CustomErrors::NonUniform(NonUniformError));
             }
         }
-        Ok(Matrix { rows, m, n })
+        Ok(Matrix {
+            data: rows.concat(),
+            m,
+            n,
+        })
     }
-    pub fn trace(&self) -> T {
-        let m = self.m;
-        let zero_cast: T = 0.into();
-        let mut trace: T = zero_cast;
-        let rows = &self.rows;
-        for i in 0..m {
-            trace += rows[i][i]
+
+    /// Builds a matrix from rows without checking them. The row count and the
+    /// length of the first row are taken as the shape, so empty or ragged
+    /// input gives a matrix whose `data` doesn't match `m * n`. Only use this
+    /// on rows already known to be valid, e.g. ones produced by `rows()`.
+    pub fn from_rows_unchecked(rows: Vec<Vec<T>>) -> Matrix<T> {
+        let m = rows.len();
+        let n = match rows.first() {
+            Some(row) => row.len(),
+            None => 0,
+        };
+        Matrix {
+            data: rows.concat(),
+            m,
+            n,
         }
-        trace
+    }
+
+    pub fn from_vec(data: Vec<T>, m: usize, n: usize) -> Result<Matrix<T>, CustomErrors> {
+        if m == 0 || n == 0 {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        if data.len() != m * n {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (m * n, 1),
+                (data.len(), 1),
+            )));
+        }
+
+        Ok(Matrix { data, m, n })
+    }
+
+    // rebuilds the nested row layout the matrix used to be stored in
+    pub fn rows(&self) -> Vec<Vec<T>> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        for i in 0..self.m {
+            rows.push(self.data[i * self.n..(i + 1) * self.n].to_vec())
+        }
+        rows
     }
 
     pub fn transpose(&self) -> Matrix<T> {
-        let mut new_matrix: Vec<Vec<T>> = vec![];
+        let mut new_data: Vec<T> = Vec::with_capacity(self.m * self.n);
         let loop1: usize = self.m;
         let loop2: usize = self.n;
 
         for j in 0..loop2 {
-            let mut transposed_row = vec![];
             for i in 0..loop1 {
-                transposed_row.push(self.rows[i][j])
+                new_data.push(self.data[i * self.n + j])
             }
-            new_matrix.push(transposed_row)
         }
         return Matrix {
-            rows: new_matrix,
+            data: new_data,
             m: self.n,
             n: self.m,
         };
     }
 
+    pub fn map<U: Copy, F: Fn(T) -> U>(&self, f: F) -> Matrix<U> {
+        let mut new_data: Vec<U> = Vec::with_capacity(self.data.len());
+        for k in 0..self.data.len() {
+            new_data.push(f(self.data[k]))
+        }
+
+        Matrix {
+            data: new_data,
+            m: self.m,
+            n: self.n,
+        }
+    }
+
+    // unwraps the result of a reduction that leaves a 1x1 matrix
+    pub fn as_scalar(&self) -> Result<T, CustomErrors> {
+        if self.m != 1 || self.n != 1 {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (1, 1),
+                (self.m, self.n),
+            )));
+        }
+        Ok(self.data[0])
+    }
+
+    // panic free counterparts to indexing, for indices that can't be trusted
+    pub fn get(&self, i: usize, j: usize) -> Option<T> {
+        if i >= self.m || j >= self.n {
+            return None;
+        }
+        Some(self.data[i * self.n + j])
+    }
+
+    pub fn set(&mut self, i: usize, j: usize, value: T) -> Result<(), CustomErrors> {
+        if i >= self.m || j >= self.n {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+        self.data[i * self.n + j] = value;
+        Ok(())
+    }
+
+    pub fn row(&self, i: usize) -> Result<Vec<T>, CustomErrors> {
+        if i >= self.m {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+        Ok(self.data[i * self.n..(i + 1) * self.n].to_vec())
+    }
+
+    pub fn col(&self, j: usize) -> Result<Vec<T>, CustomErrors> {
+        if j >= self.n {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+
+        let mut col: Vec<T> = vec![];
+        for i in 0..self.m {
+            col.push(self.data[i * self.n + j])
+        }
+        Ok(col)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.n)
+    }
+
+    // columns are strided in the row-major storage, so each one is gathered
+    // into a fresh vector
+    pub fn iter_cols(&self) -> impl Iterator<Item = Vec<T>> + '_ {
+        (0..self.n).map(move |j| {
+            let mut col: Vec<T> = vec![];
+            for i in 0..self.m {
+                col.push(self.data[i * self.n + j])
+            }
+            col
+        })
+    }
+
+    pub fn submatrix(
+        &self,
+        row_range: std::ops::Range<usize>,
+        col_range: std::ops::Range<usize>,
+    ) -> Result<Matrix<T>, CustomErrors> {
+        if row_range.is_empty() || col_range.is_empty() {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        if row_range.end > self.m || col_range.end > self.n {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+
+        let mut new_data: Vec<T> = vec![];
+        for i in row_range.clone() {
+            let start = i * self.n;
+            new_data.extend_from_slice(&self.data[start + col_range.start..start + col_range.end])
+        }
+
+        Ok(Matrix {
+            data: new_data,
+            m: row_range.len(),
+            n: col_range.len(),
+        })
+    }
+
+    pub fn flatten(&self) -> Vec<T> {
+        self.data.clone()
+    }
+
+    pub fn reshape(&self, new_m: usize, new_n: usize) -> Result<Matrix<T>, CustomErrors> {
+        if new_m * new_n != self.m * self.n {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (self.m, self.n),
+                (new_m, new_n),
+            )));
+        }
+
+        Ok(Matrix {
+            data: self.flatten(),
+            m: new_m,
+            n: new_n,
+        })
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), CustomErrors> {
+        if a >= self.m || b >= self.m {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+        for j in 0..self.n {
+            self.data.swap(a * self.n + j, b * self.n + j)
+        }
+        Ok(())
+    }
+
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> Result<(), CustomErrors> {
+        if a >= self.n || b >= self.n {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+        for i in 0..self.m {
+            self.data.swap(i * self.n + a, i * self.n + b)
+        }
+        Ok(())
+    }
+
+    // rectangular matrices change shape when transposed, so only square ones are
+    // supported here
+    pub fn transpose_in_place(&mut self) -> Result<(), CustomErrors> {
+        if self.m != self.n {
+            return Err(CustomErrors::NotImplemented(NotImplementedError));
+        }
+        for i in 0..self.n {
+            for j in (i + 1)..self.n {
+                self.data.swap(i * self.n + j, j * self.n + i)
+            }
+        }
+        Ok(())
+    }
+
+    pub fn apply<F: Fn(T) -> T>(&mut self, f: F) {
+        for k in 0..self.data.len() {
+            self.data[k] = f(self.data[k])
+        }
+    }
+}
+
+/// The default matrix is 1x1 holding a zero. `Matrix::new` never produces a
+/// matrix with an empty dimension, so the smallest valid matrix is used
+/// instead of an empty one. Replace it before relying on its shape.
+impl<T: Copy + From<u8>> Default for Matrix<T> {
+    fn default() -> Matrix<T> {
+        let zero_cast: T = 0.into();
+        Matrix {
+            data: vec![zero_cast],
+            m: 1,
+            n: 1,
+        }
+    }
+}
+
+impl<T: Copy> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = CustomErrors;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Matrix<T>, CustomErrors> {
+        Matrix::new(rows)
+    }
+}
+
+// collects rows, i.e. `rows.into_iter().map(...).collect::<Matrix<_>>()`.
+// `from_iter` can't return a Result, so ragged or empty input panics with the
+// error `Matrix::new` would have returned
+impl<T: Copy> FromIterator<Vec<T>> for Matrix<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Matrix<T> {
+        let rows: Vec<Vec<T>> = iter.into_iter().collect();
+        match Matrix::new(rows) {
+            Ok(matrix) => matrix,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+// matrices are (de)serialized as their nested rows, i.e. {"rows": [[1, 2], [3, 4]]}.
+// m and n are never stored, they're rebuilt and the rows revalidated by
+// `Matrix::new` on the way back in
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedMatrix<T> {
+    rows: Vec<Vec<T>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Copy> From<Matrix<T>> for SerializedMatrix<T> {
+    fn from(matrix: Matrix<T>) -> SerializedMatrix<T> {
+        SerializedMatrix {
+            rows: matrix.rows(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Copy> TryFrom<SerializedMatrix<T>> for Matrix<T> {
+    type Error = CustomErrors;
+
+    fn try_from(serialized: SerializedMatrix<T>) -> Result<Matrix<T>, CustomErrors> {
+        Matrix::new(serialized.rows)
+    }
+}
+
+impl<T: Copy + From<u8> + Into<f64> + std::ops::AddAssign> Matrix<T> {
+    pub fn trace(&self) -> T {
+        let m = self.m;
+        let zero_cast: T = 0.into();
+        let mut trace: T = zero_cast;
+        for i in 0..m {
+            trace += self[(i, i)]
+        }
+        trace
+    }
+
     pub fn cast_f64(&self) -> Matrix<f64> {
+        let mut new_data: Vec<f64> = Vec::with_capacity(self.data.len());
+        for k in 0..self.data.len() {
+            let new_element: f64 = self.data[k].into();
+            new_data.push(new_element)
+        }
+
+        Matrix {
+            data: new_data,
+            m: self.m,
+            n: self.n,
+        }
+    }
+}
+
+impl<T: Copy + From<u8> + std::ops::AddAssign> Matrix<T> {
+    pub fn row_sums(&self) -> Vec<T> {
+        let zero_cast: T = 0.into();
+        let mut sums: Vec<T> = vec![zero_cast; self.m];
+        for i in 0..self.m {
+            for j in 0..self.n {
+                sums[i] += self.data[i * self.n + j]
+            }
+        }
+        sums
+    }
+
+    pub fn col_sums(&self) -> Vec<T> {
+        let zero_cast: T = 0.into();
+        let mut sums: Vec<T> = vec![zero_cast; self.n];
+        for i in 0..self.m {
+            for j in 0..self.n {
+                sums[j] += self.data[i * self.n + j]
+            }
+        }
+        sums
+    }
+
+    pub fn sum(&self) -> T {
+        let mut total: T = 0.into();
+        for k in 0..self.data.len() {
+            total += self.data[k]
+        }
+        total
+    }
+}
+
+impl Matrix<f64> {
+    // elementary row operations, together with `swap_rows` from the generic
+    // impl these are the three steps of Gaussian elimination
+    pub fn scale_row(&mut self, row: usize, factor: f64) -> Result<(), CustomErrors> {
+        if row >= self.m {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+        for j in 0..self.n {
+            self.data[row * self.n + j] *= factor
+        }
+        Ok(())
+    }
+
+    // row target += factor * row source, which leaves the determinant as it
+    // is. a row can't be added to itself, that would just scale it
+    pub fn add_scaled_row(
+        &mut self,
+        target: usize,
+        source: usize,
+        factor: f64,
+    ) -> Result<(), CustomErrors> {
+        if target >= self.m || source >= self.m {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+        if target == source {
+            return Err(CustomErrors::InvalidParameter(InvalidParameterError));
+        }
+        for j in 0..self.n {
+            let value = self.data[source * self.n + j];
+            self.data[target * self.n + j] += factor * value
+        }
+        Ok(())
+    }
+
+    // bounds every element to [min, max], NaNs are passed through as they are
+    pub fn clip(&self, min: f64, max: f64) -> Matrix<f64> {
+        self.map(|v| {
+            if v < min {
+                min
+            } else if v > max {
+                max
+            } else {
+                v
+            }
+        })
+    }
+}
+
+impl<T: Copy> std::ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        let (i, j) = index;
+        if i >= self.m || j >= self.n {
+            panic!(
+                "index ({}, {}) is out of bounds for a {}x{} matrix",
+                i, j, self.m, self.n
+            );
+        }
+        &self.data[i * self.n + j]
+    }
+}
+
+impl<T: Copy> std::ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        let (i, j) = index;
+        if i >= self.m || j >= self.n {
+            panic!(
+                "index ({}, {}) is out of bounds for a {}x{} matrix",
+                i, j, self.m, self.n
+            );
+        }
+        &mut self.data[i * self.n + j]
+    }
+}
+
+impl<T: Copy + std::fmt::Display> std::fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let m = self.m;
         let n = self.n;
-        let rows = &self.rows;
-        let mut new_rows: Vec<Vec<f64>> = vec![];
+
+        // render every element first so each column can be padded
+        // to its widest entry
+        let mut cells: Vec<Vec<String>> = vec![];
+        let mut widths: Vec<usize> = vec![0; n];
         for i in 0..m {
-            let mut new_row: Vec<f64> = vec![];
+            let mut cell_row: Vec<String> = vec![];
             for j in 0..n {
-                let new_element: f64 = rows[i][j].into();
-                new_row.push(new_element)
+                let cell = self[(i, j)].to_string();
+                if cell.len() > widths[j] {
+                    widths[j] = cell.len()
+                }
+                cell_row.push(cell)
             }
-            new_rows.push(new_row)
+            cells.push(cell_row)
         }
 
-        Matrix {
-            rows: new_rows,
-            m,
-            n,
+        for i in 0..m {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for j in 0..n {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, " {:>width$}", cells[i][j], width = widths[j])?;
+            }
+            write!(f, " ]")?;
         }
+        Ok(())
     }
 }
 
@@ -101,13 +534,12 @@ pub fn standardize<
 ) -> Result<Matrix<f64>, CustomErrors> {
     let m = matrix.m;
     let n = matrix.n;
-    let rows = &matrix.rows;
     let mut new_rows = vec![];
     for j in 0..n {
         let mut new_row = vec![];
         let mut features: Vec<f64> = vec![];
         for i in 0..m {
-            let v: f64 = rows[i][j].into();
+            let v: f64 = matrix[(i, j)].into();
             features.push(v)
         }
 
@@ -138,6 +570,10 @@ pub fn standardize<
 
 #[cfg(test)]
 mod tests {
+    use crate::error::{
+        CustomErrors, EmptyVectorError, IndexOutOfBoundsError, InvalidParameterError,
+        MismatchError, NonUniformError, NotImplementedError,
+    };
     use crate::matrix::Matrix;
 
     use super::standardize;
@@ -158,4 +594,455 @@ mod tests {
 
         assert_eq!(s, targ_m)
     }
+
+    #[test]
+    fn test_map() {
+        let m = Matrix::new(vec![vec![1, -2, 3], vec![4, 5, -6]]).unwrap();
+        let squares = m.map(|x| x * x);
+
+        assert_eq!(squares.rows(), vec![vec![1, 4, 9], vec![16, 25, 36]]);
+        assert_eq!(squares.m, m.m);
+        assert_eq!(squares.n, m.n);
+
+        let halves = m.map(|x| x as f64 / 2.0);
+        assert_eq!(
+            halves.rows(),
+            vec![vec![0.5, -1.0, 1.5], vec![2.0, 2.5, -3.0]]
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut m = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        m.apply(|x| x * 2.0);
+
+        assert_eq!(m.rows(), vec![vec![2.0, 4.0], vec![6.0, 8.0]]);
+        assert_eq!(m.m, 2);
+        assert_eq!(m.n, 2);
+    }
+
+    #[test]
+    fn test_row_and_col() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+        assert_eq!(m.row(1).unwrap(), vec![4, 5, 6]);
+        assert_eq!(m.col(2).unwrap(), vec![3, 6]);
+
+        match m.row(2) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+        match m.col(3) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+    }
+
+    #[test]
+    fn test_row_operations() {
+        let mut m = Matrix::new(vec![vec![2.0, 4.0], vec![1.0, 3.0], vec![0.0, 1.0]]).unwrap();
+
+        m.scale_row(0, 0.5).unwrap();
+        assert_eq!(
+            m.rows(),
+            vec![vec![1.0, 2.0], vec![1.0, 3.0], vec![0.0, 1.0]]
+        );
+
+        m.add_scaled_row(1, 0, -1.0).unwrap();
+        assert_eq!(
+            m.rows(),
+            vec![vec![1.0, 2.0], vec![0.0, 1.0], vec![0.0, 1.0]]
+        );
+
+        m.swap_rows(0, 2).unwrap();
+        assert_eq!(
+            m.rows(),
+            vec![vec![0.0, 1.0], vec![0.0, 1.0], vec![1.0, 2.0]]
+        );
+
+        match m.scale_row(3, 2.0) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+        match m.add_scaled_row(0, 3, 1.0) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+        match m.add_scaled_row(1, 1, 1.0) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::InvalidParameter(InvalidParameterError)),
+        }
+    }
+
+    #[test]
+    fn test_add_scaled_row_keeps_determinant() {
+        let mut m = Matrix::new(vec![
+            vec![2.0, 1.0, 3.0],
+            vec![4.0, -1.0, 0.0],
+            vec![1.0, 5.0, 2.0],
+        ])
+        .unwrap();
+        let before = crate::matrix::operations::determinant(&m).unwrap();
+
+        m.add_scaled_row(1, 0, -2.0).unwrap();
+        m.add_scaled_row(2, 0, -0.5).unwrap();
+        let after = crate::matrix::operations::determinant(&m).unwrap();
+        assert!((before - after).abs() < 1e-12);
+
+        m.scale_row(2, 3.0).unwrap();
+        let scaled = crate::matrix::operations::determinant(&m).unwrap();
+        assert!((scaled - 3.0 * before).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clip() {
+        let m = Matrix::new(vec![vec![-5.0, -1.0, 0.0], vec![0.5, 1.0, 7.5]]).unwrap();
+        let clipped = m.clip(-1.0, 1.0);
+
+        assert_eq!(
+            clipped.rows(),
+            vec![vec![-1.0, -1.0, 0.0], vec![0.5, 1.0, 1.0]]
+        );
+        // the original is left alone
+        assert_eq!(m[(0, 0)], -5.0);
+
+        let with_nan = Matrix::new(vec![vec![f64::NAN, 3.0]]).unwrap();
+        let clipped = with_nan.clip(0.0, 2.0);
+        assert!(clipped[(0, 0)].is_nan());
+        assert_eq!(clipped[(0, 1)], 2.0);
+    }
+
+    #[test]
+    fn test_sums() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, -5, 6]]).unwrap();
+
+        assert_eq!(m.row_sums(), vec![6, 5]);
+        assert_eq!(m.col_sums(), vec![5, -3, 9]);
+        assert_eq!(m.sum(), 11);
+
+        let single = Matrix::new(vec![vec![7u32]]).unwrap();
+        assert_eq!(single.row_sums(), vec![7]);
+        assert_eq!(single.col_sums(), vec![7]);
+        assert_eq!(single.sum(), 7);
+    }
+
+    #[test]
+    fn test_as_scalar() {
+        let m = Matrix::new(vec![vec![42]]).unwrap();
+        assert_eq!(m.as_scalar().unwrap(), 42);
+
+        // xᵀx reduces to a single value
+        let x = Matrix::new(vec![vec![1.0], vec![2.0], vec![2.0]]).unwrap();
+        let product = crate::matrix::operations::multiply_matrices(&x.transpose(), &x).unwrap();
+        assert_eq!(product.as_scalar().unwrap(), 9.0);
+
+        let square = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        match square.as_scalar() {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((1, 1), (2, 2)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+        assert_eq!(m.get(0, 0), Some(1));
+        assert_eq!(m.get(1, 2), Some(6));
+        assert_eq!(m.get(2, 0), None);
+        assert_eq!(m.get(0, 3), None);
+
+        m.set(1, 0, 40).unwrap();
+        assert_eq!(m.get(1, 0), Some(40));
+        assert_eq!(m[(1, 0)], 40);
+
+        match m.set(2, 0, 7) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+        match m.set(0, 3, 7) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+        assert_eq!(m.rows(), vec![vec![1, 2, 3], vec![40, 5, 6]]);
+    }
+
+    #[test]
+    fn test_swap_rows() {
+        let mut m = Matrix::new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+
+        m.swap_rows(0, 2).unwrap();
+        assert_eq!(m.rows(), vec![vec![5, 6], vec![3, 4], vec![1, 2]]);
+
+        m.swap_rows(1, 1).unwrap();
+        assert_eq!(m.rows(), vec![vec![5, 6], vec![3, 4], vec![1, 2]]);
+
+        match m.swap_rows(0, 3) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+    }
+
+    #[test]
+    fn test_swap_cols() {
+        let mut m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+        m.swap_cols(0, 1).unwrap();
+        assert_eq!(m.rows(), vec![vec![2, 1, 3], vec![5, 4, 6]]);
+
+        m.swap_cols(2, 2).unwrap();
+        assert_eq!(m.rows(), vec![vec![2, 1, 3], vec![5, 4, 6]]);
+
+        match m.swap_cols(3, 0) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+    }
+
+    #[test]
+    fn test_transpose_in_place() {
+        let mut m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+        let target = m.transpose();
+
+        m.transpose_in_place().unwrap();
+        assert_eq!(m, target);
+
+        let mut rect = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        match rect.transpose_in_place() {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+        assert_eq!(rect.rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_iter_rows() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let rows: Vec<&[i32]> = m.iter_rows().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+
+        let sums: Vec<i32> = m.iter_rows().map(|row| row.iter().sum()).collect();
+        assert_eq!(sums, vec![6, 15]);
+    }
+
+    #[test]
+    fn test_iter_cols() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let cols: Vec<Vec<i32>> = m.iter_cols().collect();
+        assert_eq!(cols, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn test_submatrix() {
+        let m = Matrix::new(vec![
+            vec![1, 2, 3, 4],
+            vec![5, 6, 7, 8],
+            vec![9, 10, 11, 12],
+            vec![13, 14, 15, 16],
+        ])
+        .unwrap();
+        let target = Matrix::new(vec![vec![6, 7], vec![10, 11]]).unwrap();
+
+        assert_eq!(m.submatrix(1..3, 1..3).unwrap(), target);
+
+        match m.submatrix(0..2, 3..5) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+        match m.submatrix(2..2, 0..2) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_flatten() {
+        let m = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(m.flatten(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_reshape() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+        let target = Matrix::new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+        assert_eq!(m.reshape(3, 2).unwrap(), target);
+
+        let target = Matrix::new(vec![vec![1, 2, 3, 4, 5, 6]]).unwrap();
+        assert_eq!(m.reshape(1, 6).unwrap(), target);
+
+        match m.reshape(4, 2) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 3), (4, 2)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_matrix_macro() {
+        let m = crate::matrix![1, 2; 3, 4];
+        assert_eq!(m, Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap());
+
+        let m = crate::matrix![1.0, 2.0, 3.0];
+        assert_eq!(m, Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap());
+        assert_eq!((m.m, m.n), (1, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "NonUniform")]
+    fn test_matrix_macro_ragged() {
+        crate::matrix![1, 2; 3];
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let m: Matrix<i32> = (1..=3).map(|i| vec![i, i * 10]).collect();
+        assert_eq!(m.rows(), vec![vec![1, 10], vec![2, 20], vec![3, 30]]);
+
+        let doubled: Matrix<i32> = m.rows().into_iter().map(|row| vec![row[0] * 2]).collect();
+        assert_eq!(doubled.rows(), vec![vec![2], vec![4], vec![6]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix rows are not all the same length.")]
+    fn test_from_iterator_ragged() {
+        let _: Matrix<i32> = vec![vec![1, 2], vec![3]].into_iter().collect();
+    }
+
+    #[test]
+    fn test_default() {
+        let m: Matrix<f64> = Matrix::default();
+        assert_eq!((m.m, m.n), (1, 1));
+        assert_eq!(m.data, vec![0.0]);
+
+        #[derive(Default)]
+        struct Layer {
+            weights: Matrix<i32>,
+        }
+        let layer = Layer::default();
+        assert_eq!(layer.weights.rows(), vec![vec![0]]);
+    }
+
+    #[test]
+    fn test_try_from_rows() {
+        let m = Matrix::try_from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(m, Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap());
+
+        let result: Result<Matrix<i32>, CustomErrors> = vec![vec![1, 2], vec![3]].try_into();
+        match result {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NonUniform(NonUniformError)),
+        }
+
+        let empty: Vec<Vec<i32>> = vec![];
+        match Matrix::try_from(empty) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_from_rows_unchecked() {
+        let rows = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+        let m = Matrix::from_rows_unchecked(rows.clone());
+        assert_eq!(m, Matrix::new(rows).unwrap());
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let m = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+        assert_eq!(m, Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap());
+        assert_eq!(m[(1, 0)], 4);
+
+        match Matrix::from_vec(vec![1, 2, 3], 2, 2) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((4, 1), (3, 1)))
+            ),
+        }
+        match Matrix::<i32>::from_vec(vec![], 0, 2) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_rows() {
+        let rows = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+        let m = Matrix::new(rows.clone()).unwrap();
+        assert_eq!(m.data, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.rows(), rows);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let m = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, r#"{"rows":[[1.0,2.0,3.0],[4.0,5.0,6.0]]}"#);
+
+        let loaded: Matrix<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, m);
+        assert_eq!((loaded.m, loaded.n), (2, 3));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_validates_rows() {
+        let ragged: Result<Matrix<i32>, serde_json::Error> =
+            serde_json::from_str(r#"{"rows":[[1,2,3],[4,5]]}"#);
+        match ragged {
+            Ok(_) => assert!(false),
+            Err(err) => assert!(err
+                .to_string()
+                .contains("Matrix rows are not all the same length.")),
+        }
+
+        let empty: Result<Matrix<i32>, serde_json::Error> = serde_json::from_str(r#"{"rows":[]}"#);
+        match empty {
+            Ok(_) => assert!(false),
+            Err(err) => assert!(err.to_string().contains("Vector or matrix is empty.")),
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let m = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(m.to_string(), "[ 1  2 ]\n[ 3  4 ]");
+
+        let m = Matrix::new(vec![vec![1, 20], vec![300, 4]]).unwrap();
+        assert_eq!(m.to_string(), "[   1  20 ]\n[ 300   4 ]");
+    }
+
+    #[test]
+    fn test_index() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+        assert_eq!(m[(0, 0)], 1);
+        assert_eq!(m[(0, 2)], 3);
+        assert_eq!(m[(1, 1)], 5);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut m = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        m[(1, 0)] = 7;
+
+        assert_eq!(m[(1, 0)], 7);
+        assert_eq!(m.rows(), vec![vec![1, 2], vec![7, 4]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (2, 0) is out of bounds for a 2x2 matrix")]
+    fn test_index_out_of_bounds() {
+        let m = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let _ = m[(2, 0)];
+    }
 }
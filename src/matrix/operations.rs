@@ -1,149 +1,506 @@
 use crate::error::{self, CustomErrors, MismatchError};
+use crate::matrix::decomposition::lu_decompose;
 use crate::matrix::logic::{can_add, can_multiply, is_square};
 use crate::matrix::Matrix;
-use crate::vector::operations::{add_vec, sub_vec};
+use crate::vector::operations::{add_vec, multiply_vec, sub_vec};
 use crate::vector::util;
 
 pub fn add_matrices<T: std::marker::Copy + std::ops::Add<Output = T>>(
-    matrix_1: Matrix<T>,
-    matrix_2: Matrix<T>,
+    matrix_1: &Matrix<T>,
+    matrix_2: &Matrix<T>,
 ) -> Result<Matrix<T>, error::CustomErrors> {
-    if !can_add(&matrix_1, &matrix_2) {
-        return Err(error::CustomErrors::Mismatch(error::MismatchError));
+    if !can_add(matrix_1, matrix_2) {
+        return Err(error::CustomErrors::Mismatch(error::MismatchError::new(
+            (matrix_1.m, matrix_1.n),
+            (matrix_2.m, matrix_2.n),
+        )));
     }
 
-    let mut new_matrix_rows: Vec<Vec<T>> = vec![];
-    for i in 0..matrix_1.m {
-        let new_row = match add_vec(&matrix_1.rows[i], &matrix_2.rows[i]) {
-            Ok(vec) => vec,
-            Err(e) => return Err(e),
-        };
-        new_matrix_rows.push(new_row)
-    }
+    let new_data = match add_vec(&matrix_1.data, &matrix_2.data) {
+        Ok(vec) => vec,
+        Err(e) => return Err(e),
+    };
     Ok(Matrix {
-        rows: new_matrix_rows,
+        data: new_data,
         m: matrix_1.m,
         n: matrix_1.n,
     })
 }
 
-pub fn sub_matrices<T: std::marker::Copy + std::ops::Sub<Output = T>>(
-    matrix_1: Matrix<T>,
-    matrix_2: Matrix<T>,
+pub fn subtract_matrices<T: std::marker::Copy + std::ops::Sub<Output = T>>(
+    matrix_1: &Matrix<T>,
+    matrix_2: &Matrix<T>,
 ) -> Result<Matrix<T>, error::CustomErrors> {
-    if !can_add(&matrix_1, &matrix_2) {
-        return Err(error::CustomErrors::Mismatch(error::MismatchError));
+    if !can_add(matrix_1, matrix_2) {
+        return Err(error::CustomErrors::Mismatch(error::MismatchError::new(
+            (matrix_1.m, matrix_1.n),
+            (matrix_2.m, matrix_2.n),
+        )));
+    }
+
+    let new_data = match sub_vec(&matrix_1.data, &matrix_2.data) {
+        Ok(vec) => vec,
+        Err(e) => return Err(e),
+    };
+    Ok(Matrix {
+        data: new_data,
+        m: matrix_1.m,
+        n: matrix_1.n,
+    })
+}
+
+pub fn hstack<T: Copy>(left: &Matrix<T>, right: &Matrix<T>) -> Result<Matrix<T>, CustomErrors> {
+    if left.m != right.m {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (left.m, right.n),
+            (right.m, right.n),
+        )));
+    }
+
+    let mut new_data: Vec<T> = Vec::with_capacity(left.m * (left.n + right.n));
+    for i in 0..left.m {
+        new_data.extend_from_slice(&left.data[i * left.n..(i + 1) * left.n]);
+        new_data.extend_from_slice(&right.data[i * right.n..(i + 1) * right.n]);
+    }
+
+    Ok(Matrix {
+        data: new_data,
+        m: left.m,
+        n: left.n + right.n,
+    })
+}
+
+pub fn vstack<T: Copy>(top: &Matrix<T>, bottom: &Matrix<T>) -> Result<Matrix<T>, CustomErrors> {
+    if top.n != bottom.n {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (bottom.m, top.n),
+            (bottom.m, bottom.n),
+        )));
+    }
+
+    let mut new_data = top.data.clone();
+    new_data.extend_from_slice(&bottom.data);
+
+    Ok(Matrix {
+        data: new_data,
+        m: top.m + bottom.m,
+        n: top.n,
+    })
+}
+
+// assembles a grid of blocks, i.e. [[A, B], [C, D]]. blocks in a grid row
+// must share a row count and blocks in a grid column a column count
+pub fn block_matrix<T: Copy>(blocks: &Vec<Vec<Matrix<T>>>) -> Result<Matrix<T>, CustomErrors> {
+    if blocks.is_empty() || blocks[0].is_empty() {
+        return Err(CustomErrors::EmptyVector(error::EmptyVectorError));
+    }
+
+    let grid_n = blocks[0].len();
+    for r in 0..blocks.len() {
+        if blocks[r].len() != grid_n {
+            return Err(CustomErrors::NonUniform(error::NonUniformError));
+        }
+
+        for c in 0..grid_n {
+            let block = &blocks[r][c];
+            let height = blocks[r][0].m;
+            let width = blocks[0][c].n;
+            if block.m != height || block.n != width {
+                return Err(CustomErrors::Mismatch(MismatchError::new(
+                    (height, width),
+                    (block.m, block.n),
+                )));
+            }
+        }
     }
 
-    let mut new_matrix_rows: Vec<Vec<T>> = vec![];
-    for i in 0..matrix_1.m {
-        let new_row = match sub_vec(&matrix_1.rows[i], &matrix_2.rows[i]) {
-            Ok(vec) => vec,
-            Err(e) => return Err(e),
+    // join each grid row side by side, then stack the rows
+    let mut bands: Vec<Matrix<T>> = vec![];
+    for r in 0..blocks.len() {
+        let mut band = blocks[r][0].clone();
+        for c in 1..grid_n {
+            band = match hstack(&band, &blocks[r][c]) {
+                Ok(band) => band,
+                Err(err) => return Err(err),
+            };
+        }
+        bands.push(band)
+    }
+
+    let mut result = bands[0].clone();
+    for r in 1..bands.len() {
+        result = match vstack(&result, &bands[r]) {
+            Ok(stacked) => stacked,
+            Err(err) => return Err(err),
         };
-        new_matrix_rows.push(new_row)
     }
+
+    Ok(result)
+}
+
+pub fn hadamard_product<T: Copy + std::ops::Mul<Output = T>>(
+    matrix_1: &Matrix<T>,
+    matrix_2: &Matrix<T>,
+) -> Result<Matrix<T>, CustomErrors> {
+    if !can_add(matrix_1, matrix_2) {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (matrix_1.m, matrix_1.n),
+            (matrix_2.m, matrix_2.n),
+        )));
+    }
+
+    let new_data = match multiply_vec(&matrix_1.data, &matrix_2.data) {
+        Ok(vec) => vec,
+        Err(e) => return Err(e),
+    };
     Ok(Matrix {
-        rows: new_matrix_rows,
+        data: new_data,
         m: matrix_1.m,
         n: matrix_1.n,
     })
 }
 
-pub fn scalar_multiply<
-    T: Copy
-        + Into<f64>
-        + std::ops::Mul
-        + std::ops::Mul<Output = T>
-        + std::convert::From<u8>
-        + std::ops::AddAssign,
->(
-    matrix: &Matrix<T>,
-    scalar: T,
-) -> Result<Matrix<T>, error::CustomErrors> {
-    let rows = &matrix.rows;
-    let m = matrix.m;
-    let n = matrix.n;
+pub fn outer_product<T: Copy + std::ops::Mul<Output = T>>(
+    a: &Vec<T>,
+    b: &Vec<T>,
+) -> Result<Matrix<T>, CustomErrors> {
+    if a.is_empty() || b.is_empty() {
+        return Err(CustomErrors::EmptyVector(error::EmptyVectorError));
+    }
 
-    let mut new_rows: Vec<Vec<T>> = vec![];
+    let mut new_data: Vec<T> = Vec::with_capacity(a.len() * b.len());
+    for i in 0..a.len() {
+        for j in 0..b.len() {
+            new_data.push(a[i] * b[j])
+        }
+    }
 
-    for i in 0..m {
-        let mut new_row: Vec<T> = vec![];
-        for j in 0..n {
-            let new_val = rows[i][j] * scalar;
-            new_row.push(new_val)
+    Ok(Matrix {
+        data: new_data,
+        m: a.len(),
+        n: b.len(),
+    })
+}
+
+pub fn kronecker<T: Copy + std::ops::Mul<Output = T>>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let m = a.m * b.m;
+    let n = a.n * b.n;
+
+    // row i * b.m + k of the result is row i of a combined with row k of b
+    let mut new_data: Vec<T> = Vec::with_capacity(m * n);
+    for i in 0..a.m {
+        for k in 0..b.m {
+            for j in 0..a.n {
+                for l in 0..b.n {
+                    new_data.push(a[(i, j)] * b[(k, l)])
+                }
+            }
         }
-        new_rows.push(new_row)
     }
 
-    match Matrix::new(new_rows) {
-        Ok(matrix) => Ok(matrix),
-        Err(err) => Err(err),
+    Matrix {
+        data: new_data,
+        m,
+        n,
     }
 }
 
+pub fn scalar_multiply<T: Copy + std::ops::Mul<Output = T>>(
+    matrix: &Matrix<T>,
+    scalar: T,
+) -> Matrix<T> {
+    matrix.map(|element| element * scalar)
+}
+
 pub fn scalar_divide<T: Copy + Into<f64> + std::ops::Div + std::ops::Div<Output = T>>(
     matrix: &Matrix<T>,
     scalar: T,
 ) -> Result<Matrix<f64>, CustomErrors> {
-    let rows = &matrix.rows;
-    let m = matrix.m;
-    let n = matrix.n;
     let scalar: f64 = scalar.into();
 
-    let mut new_rows: Vec<Vec<f64>> = vec![];
+    Ok(matrix.map(|element| {
+        let old_val: f64 = element.into();
+        old_val / scalar
+    }))
+}
+
+pub fn multiply_matrices<
+    T: Copy + From<u8> + std::ops::Mul + std::ops::AddAssign<<T as std::ops::Mul>::Output>,
+>(
+    matrix_1: &Matrix<T>,
+    matrix_2: &Matrix<T>,
+) -> Result<Matrix<T>, error::CustomErrors> {
+    if matrix_1.m == 0 || matrix_1.n == 0 || matrix_2.m == 0 || matrix_2.n == 0 {
+        return Err(error::CustomErrors::EmptyVector(error::EmptyVectorError));
+    }
+
+    if !can_multiply(&matrix_1, &matrix_2) {
+        return Err(error::CustomErrors::Mismatch(error::MismatchError::new(
+            (matrix_1.n, matrix_2.n),
+            (matrix_2.m, matrix_2.n),
+        )));
+    }
 
+    let m = matrix_1.m;
+    let n = matrix_2.n;
+    let inner = matrix_1.n;
+    let a = &matrix_1.data;
+    let b = &matrix_2.data;
+    let zero_cast: T = 0.into();
+    let mut new_data: Vec<T> = vec![zero_cast; m * n];
+
+    // i-k-j ordering walks both the output row and the row of matrix_2
+    // contiguously, which is much friendlier to the cache than i-j-k
     for i in 0..m {
-        let mut new_row: Vec<f64> = vec![];
-        for j in 0..n {
-            let old_val: f64 = rows[i][j].into();
-            let new_val = old_val / scalar;
-            new_row.push(new_val)
+        for k in 0..inner {
+            let a_ik = a[i * inner + k];
+            for j in 0..n {
+                new_data[i * n + j] += a_ik * b[k * n + j]
+            }
         }
-        new_rows.push(new_row)
     }
 
-    match Matrix::new(new_rows) {
-        Ok(matrix) => Ok(matrix),
-        Err(err) => Err(err),
-    }
+    Ok(Matrix {
+        data: new_data,
+        m: matrix_1.m,
+        n: matrix_2.n,
+    })
 }
 
-pub fn multiply_matrices<
-    T: Copy + From<u8> + std::ops::Mul + std::ops::AddAssign<<T as std::ops::Mul>::Output>,
+// same i-k-j product as `multiply_matrices` with each output row handed to
+// the rayon thread pool, so the results are identical to the serial version
+#[cfg(feature = "rayon")]
+pub fn multiply_matrices_parallel<
+    T: Copy
+        + From<u8>
+        + Send
+        + Sync
+        + std::ops::Mul
+        + std::ops::AddAssign<<T as std::ops::Mul>::Output>,
 >(
     matrix_1: &Matrix<T>,
     matrix_2: &Matrix<T>,
 ) -> Result<Matrix<T>, error::CustomErrors> {
+    use rayon::prelude::*;
+
     if matrix_1.m == 0 || matrix_1.n == 0 || matrix_2.m == 0 || matrix_2.n == 0 {
         return Err(error::CustomErrors::EmptyVector(error::EmptyVectorError));
     }
 
     if !can_multiply(&matrix_1, &matrix_2) {
-        return Err(error::CustomErrors::Mismatch(error::MismatchError));
+        return Err(error::CustomErrors::Mismatch(error::MismatchError::new(
+            (matrix_1.n, matrix_2.n),
+            (matrix_2.m, matrix_2.n),
+        )));
     }
 
-    let mut new_matrix_rows: Vec<Vec<T>> = vec![];
+    let m = matrix_1.m;
+    let n = matrix_2.n;
+    let inner = matrix_1.n;
+    let a = &matrix_1.data;
+    let b = &matrix_2.data;
+    let zero_cast: T = 0.into();
+    let mut new_data: Vec<T> = vec![zero_cast; m * n];
 
-    for i in 0..matrix_1.m {
-        let mut new_row: Vec<T> = vec![];
-        for j in 0..matrix_2.n {
-            let mut element_sum: T = 0.into();
-            for k in 0..matrix_1.n {
-                element_sum += matrix_1.rows[i][k] * matrix_2.rows[k][j]
+    new_data
+        .par_chunks_mut(n)
+        .enumerate()
+        .for_each(|(i, new_row)| {
+            for k in 0..inner {
+                let a_ik = a[i * inner + k];
+                for j in 0..n {
+                    new_row[j] += a_ik * b[k * n + j]
+                }
             }
-            new_row.push(element_sum)
-        }
-        new_matrix_rows.push(new_row)
-    }
+        });
 
     Ok(Matrix {
-        rows: new_matrix_rows,
+        data: new_data,
         m: matrix_1.m,
         n: matrix_2.n,
     })
 }
 
+const STRASSEN_THRESHOLD: usize = 64;
+
+pub fn strassen_multiply(a: &Matrix<f64>, b: &Matrix<f64>) -> Result<Matrix<f64>, CustomErrors> {
+    if a.m == 0 || a.n == 0 || b.m == 0 || b.n == 0 {
+        return Err(CustomErrors::EmptyVector(error::EmptyVectorError));
+    }
+
+    if !can_multiply(a, b) {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (a.n, b.n),
+            (b.m, b.n),
+        )));
+    }
+
+    // padding goes up to the largest dimension squared, so only recurse on
+    // products that are big in every dimension and close to square. a skewed
+    // shape like 1 x 100000 times 100000 x 1 would otherwise pad to a
+    // 131072 x 131072 matrix
+    let largest = std::cmp::max(a.m, std::cmp::max(a.n, b.n));
+    let smallest = std::cmp::min(a.m, std::cmp::min(a.n, b.n));
+    if smallest <= STRASSEN_THRESHOLD || largest > 2 * smallest {
+        return multiply_matrices(a, b);
+    }
+
+    // the recursion halves the matrices every step, so zero pad both up to
+    // the next power of two and crop the product back down at the end
+    let size = largest.next_power_of_two();
+    let product = strassen_square(&pad_matrix(a, size), &pad_matrix(b, size));
+
+    product.submatrix(0..a.m, 0..b.n)
+}
+
+fn pad_matrix(matrix: &Matrix<f64>, size: usize) -> Matrix<f64> {
+    let mut padded = Matrix {
+        data: vec![0.0; size * size],
+        m: size,
+        n: size,
+    };
+    for i in 0..matrix.m {
+        for j in 0..matrix.n {
+            padded.data[i * size + j] = matrix.data[i * matrix.n + j]
+        }
+    }
+    padded
+}
+
+fn split_quadrants(matrix: &Matrix<f64>) -> [Matrix<f64>; 4] {
+    let half = matrix.n / 2;
+    let quadrant = |row_offset: usize, col_offset: usize| {
+        let mut data: Vec<f64> = Vec::with_capacity(half * half);
+        for i in 0..half {
+            let start = (i + row_offset) * matrix.n + col_offset;
+            data.extend_from_slice(&matrix.data[start..start + half])
+        }
+        Matrix {
+            data,
+            m: half,
+            n: half,
+        }
+    };
+
+    [
+        quadrant(0, 0),
+        quadrant(0, half),
+        quadrant(half, 0),
+        quadrant(half, half),
+    ]
+}
+
+// a and b are both square with a power of two size
+fn strassen_square(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64> {
+    let size = a.n;
+    if size <= STRASSEN_THRESHOLD {
+        return a * b;
+    }
+
+    let [a11, a12, a21, a22] = split_quadrants(a);
+    let [b11, b12, b21, b22] = split_quadrants(b);
+
+    let m1 = strassen_square(&(&a11 + &a22), &(&b11 + &b22));
+    let m2 = strassen_square(&(&a21 + &a22), &b11);
+    let m3 = strassen_square(&a11, &(&b12 - &b22));
+    let m4 = strassen_square(&a22, &(&b21 - &b11));
+    let m5 = strassen_square(&(&a11 + &a12), &b22);
+    let m6 = strassen_square(&(&a21 - &a11), &(&b11 + &b12));
+    let m7 = strassen_square(&(&a12 - &a22), &(&b21 + &b22));
+
+    let c11 = &(&(&m1 + &m4) - &m5) + &m7;
+    let c12 = &m3 + &m5;
+    let c21 = &m2 + &m4;
+    let c22 = &(&(&m1 - &m2) + &m3) + &m6;
+
+    let half = size / 2;
+    let mut data: Vec<f64> = Vec::with_capacity(size * size);
+    for i in 0..half {
+        data.extend_from_slice(&c11.data[i * half..(i + 1) * half]);
+        data.extend_from_slice(&c12.data[i * half..(i + 1) * half]);
+    }
+    for i in 0..half {
+        data.extend_from_slice(&c21.data[i * half..(i + 1) * half]);
+        data.extend_from_slice(&c22.data[i * half..(i + 1) * half]);
+    }
+
+    Matrix {
+        data,
+        m: size,
+        n: size,
+    }
+}
+
+pub fn trace<T: Copy + std::ops::AddAssign + From<u8>>(
+    matrix: &Matrix<T>,
+) -> Result<T, CustomErrors> {
+    if !is_square(matrix) {
+        return Err(CustomErrors::NotImplemented(error::NotImplementedError));
+    }
+
+    let mut trace: T = 0.into();
+    for i in 0..matrix.m {
+        trace += matrix[(i, i)]
+    }
+
+    Ok(trace)
+}
+
+pub fn diagonal<T: Copy>(matrix: &Matrix<T>) -> Result<Vec<T>, CustomErrors> {
+    if matrix.m == 0 || matrix.n == 0 {
+        return Err(CustomErrors::EmptyVector(error::EmptyVectorError));
+    }
+
+    let size = std::cmp::min(matrix.m, matrix.n);
+    let mut diagonal: Vec<T> = vec![];
+    for i in 0..size {
+        diagonal.push(matrix[(i, i)])
+    }
+
+    Ok(diagonal)
+}
+
+pub fn matrix_power<
+    T: Copy + From<u8> + std::ops::Mul + std::ops::AddAssign<<T as std::ops::Mul>::Output>,
+>(
+    matrix: &Matrix<T>,
+    exponent: u32,
+) -> Result<Matrix<T>, CustomErrors> {
+    if !is_square(matrix) {
+        return Err(CustomErrors::NotImplemented(error::NotImplementedError));
+    }
+
+    let mut result: Matrix<T> = match util::create_identity_matrix(matrix.m) {
+        Ok(identity) => identity,
+        Err(err) => return Err(err),
+    };
+
+    // exponentiation by squaring, square the base for every bit of the
+    // exponent and multiply it into the result when the bit is set
+    let mut base = matrix.clone();
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = match multiply_matrices(&result, &base) {
+                Ok(result) => result,
+                Err(err) => return Err(err),
+            };
+        }
+        exponent /= 2;
+        if exponent > 0 {
+            base = match multiply_matrices(&base, &base) {
+                Ok(base) => base,
+                Err(err) => return Err(err),
+            };
+        }
+    }
+
+    Ok(result)
+}
+
+/// Determinant via expansion over all n! permutations. Exact for integer
+/// matrices but only practical for small sizes, prefer `determinant_lu`
+/// for large floating point matrices.
 pub fn get_determinant<
     T: From<u8>
         + From<i32>
@@ -161,8 +518,7 @@ pub fn get_determinant<
         ));
     }
 
-    let size = matrix.rows.len();
-    let rows = &matrix.rows;
+    let size = matrix.m;
     let perms = util::get_perms(size);
     let zero_cast: T = 0.into();
     let one_cast: T = 1.into();
@@ -172,7 +528,7 @@ pub fn get_determinant<
         let sign: T = sign.into();
         let mut term: T = one_cast;
         for i in 0..size {
-            term *= rows[i][perm[i]]
+            term *= matrix[(i, perm[i])]
         }
         determinant += sign * term;
     }
@@ -180,22 +536,150 @@ pub fn get_determinant<
     Ok(determinant)
 }
 
+/// Determinant as the product of the diagonal of `U` from an LU
+/// decomposition, times the sign of the pivot permutation. Runs in O(n^3)
+/// and is the recommended path for large floating point matrices.
+pub fn determinant_lu(matrix: &Matrix<f64>) -> Result<f64, CustomErrors> {
+    let (_, upper, permutation) = match lu_decompose(matrix) {
+        Ok(lu) => lu,
+        Err(err) => return Err(err),
+    };
+
+    let sign: f64 = util::get_permutation_sign(permutation).into();
+    let mut determinant = sign;
+    for i in 0..upper.m {
+        determinant *= upper[(i, i)]
+    }
+
+    Ok(determinant)
+}
+
+/// Determinant that picks its method by size. Matrices up to 3x3 use
+/// cofactor expansion, which needs no division and so stays exact for
+/// integer valued entries, larger ones go through `determinant_lu`.
+pub fn determinant(matrix: &Matrix<f64>) -> Result<f64, CustomErrors> {
+    if !is_square(matrix) {
+        return Err(CustomErrors::NotImplemented(error::NotImplementedError));
+    }
+
+    let a = matrix;
+    match matrix.n {
+        1 => Ok(a[(0, 0)]),
+        2 => Ok(a[(0, 0)] * a[(1, 1)] - a[(0, 1)] * a[(1, 0)]),
+        // expansion along the first row
+        3 => Ok(a[(0, 0)] * (a[(1, 1)] * a[(2, 2)] - a[(1, 2)] * a[(2, 1)])
+            - a[(0, 1)] * (a[(1, 0)] * a[(2, 2)] - a[(1, 2)] * a[(2, 0)])
+            + a[(0, 2)] * (a[(1, 0)] * a[(2, 1)] - a[(1, 1)] * a[(2, 0)])),
+        _ => determinant_lu(matrix),
+    }
+}
+
+pub fn norm(matrix: &Matrix<f64>, p: u32) -> f64 {
+    let data = &matrix.data;
+    let mut sum = 0.0;
+
+    // p = 0 isn't a true norm, follow the usual convention of counting
+    // the nonzero elements
+    if p == 0 {
+        for k in 0..data.len() {
+            if data[k] != 0.0 {
+                sum += 1.0
+            }
+        }
+        return sum;
+    }
+
+    for k in 0..data.len() {
+        sum += data[k].abs().powi(p as i32)
+    }
+
+    sum.powf(1.0 / p as f64)
+}
+
+pub fn frobenius_norm(matrix: &Matrix<f64>) -> f64 {
+    norm(matrix, 2)
+}
+
+// (i, j, value) of the largest element, the first one in row-major order
+// wins ties. NaNs never compare greater, so they're skipped unless every
+// element is NaN
+pub fn max_element(matrix: &Matrix<f64>) -> Option<(usize, usize, f64)> {
+    if matrix.data.is_empty() {
+        return None;
+    }
+
+    let mut best = 0;
+    for k in 1..matrix.data.len() {
+        if matrix.data[k] > matrix.data[best] || matrix.data[best].is_nan() {
+            best = k
+        }
+    }
+    Some((best / matrix.n, best % matrix.n, matrix.data[best]))
+}
+
+pub fn min_element(matrix: &Matrix<f64>) -> Option<(usize, usize, f64)> {
+    if matrix.data.is_empty() {
+        return None;
+    }
+
+    let mut best = 0;
+    for k in 1..matrix.data.len() {
+        if matrix.data[k] < matrix.data[best] || matrix.data[best].is_nan() {
+            best = k
+        }
+    }
+    Some((best / matrix.n, best % matrix.n, matrix.data[best]))
+}
+
+pub fn abs(matrix: &Matrix<f64>) -> Matrix<f64> {
+    matrix.map(|v| v.abs())
+}
+
+// unlike f64::signum, zeros map to 0 rather than ±1
+pub fn signum(matrix: &Matrix<f64>) -> Matrix<f64> {
+    matrix.map(|v| {
+        if v > 0.0 {
+            1.0
+        } else if v < 0.0 {
+            -1.0
+        } else {
+            v
+        }
+    })
+}
+
+// predicted class per row of a score matrix, ties go to the lowest index
+pub fn argmax_rows(matrix: &Matrix<f64>) -> Vec<usize> {
+    let mut indices: Vec<usize> = Vec::with_capacity(matrix.m);
+    for row in matrix.iter_rows() {
+        let mut best = 0;
+        for j in 1..row.len() {
+            if row[j] > row[best] {
+                best = j
+            }
+        }
+        indices.push(best)
+    }
+    indices
+}
+
 pub fn multiply_matrix_vector(mat: &Matrix<f64>, vec: &Vec<f64>) -> Result<Vec<f64>, CustomErrors> {
     let m = mat.m;
     let n = mat.n;
 
     if n != vec.len() {
-        return Err(CustomErrors::Mismatch(MismatchError));
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (n, 1),
+            (vec.len(), 1),
+        )));
     }
 
-    let rows = &mat.rows;
-
     let mut prod: Vec<f64> = vec![];
 
     for i in 0..m {
         let mut row_prod = 0.0;
         for j in 0..n {
-            let mat_item = rows[i][j];
+            let mat_item = mat[(i, j)];
             let vec_item = vec[j];
             row_prod += mat_item * vec_item
         }
@@ -205,6 +689,113 @@ pub fn multiply_matrix_vector(mat: &Matrix<f64>, vec: &Vec<f64>) -> Result<Vec<f
     Ok(prod)
 }
 
+// generic counterpart to `multiply_matrix_vector`, with the same bounds as
+// `multiply_matrices` so integer and complex matrices work too
+pub fn matvec<
+    T: Copy + From<u8> + std::ops::Mul + std::ops::AddAssign<<T as std::ops::Mul>::Output>,
+>(
+    matrix: &Matrix<T>,
+    v: &Vec<T>,
+) -> Result<Vec<T>, CustomErrors> {
+    if v.len() != matrix.n {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (matrix.n, 1),
+            (v.len(), 1),
+        )));
+    }
+
+    let zero_cast: T = 0.into();
+    let mut prod: Vec<T> = vec![zero_cast; matrix.m];
+    for i in 0..matrix.m {
+        for j in 0..matrix.n {
+            prod[i] += matrix.data[i * matrix.n + j] * v[j]
+        }
+    }
+
+    Ok(prod)
+}
+
+// operator overloads, these panic on dimension mismatches instead of
+// returning a Result so they can be used inline i.e. `a + b * c`. Use the
+// functions above when errors need to be handled.
+
+impl<T: Copy + std::ops::Add<Output = T>> std::ops::Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
+        match add_matrices(self, other) {
+            Ok(matrix) => matrix,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+impl<T: Copy + std::ops::Add<Output = T>> std::ops::Add for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other: Matrix<T>) -> Matrix<T> {
+        &self + &other
+    }
+}
+
+impl<T: Copy + std::ops::Sub<Output = T>> std::ops::Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: &Matrix<T>) -> Matrix<T> {
+        match subtract_matrices(self, other) {
+            Ok(matrix) => matrix,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+impl<T: Copy + std::ops::Sub<Output = T>> std::ops::Sub for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: Matrix<T>) -> Matrix<T> {
+        &self - &other
+    }
+}
+
+impl<T: Copy + From<u8> + std::ops::Mul<Output = T> + std::ops::AddAssign> std::ops::Mul
+    for &Matrix<T>
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        match multiply_matrices(self, other) {
+            Ok(matrix) => matrix,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+impl<T: Copy + From<u8> + std::ops::Mul<Output = T> + std::ops::AddAssign> std::ops::Mul
+    for Matrix<T>
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, other: Matrix<T>) -> Matrix<T> {
+        &self * &other
+    }
+}
+
+impl<T: Copy + std::ops::Mul<Output = T>> std::ops::Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Matrix<T> {
+        scalar_multiply(&self, scalar)
+    }
+}
+
+impl<T: Copy + std::ops::Mul<Output = T>> std::ops::Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Matrix<T> {
+        scalar_multiply(self, scalar)
+    }
+}
+
 //tests......
 // ------------------------------
 // ------------------------------
@@ -212,11 +803,17 @@ pub fn multiply_matrix_vector(mat: &Matrix<f64>, vec: &Vec<f64>) -> Result<Vec<f
 #[cfg(test)]
 mod tests {
     use crate::{
-        error::{CustomErrors, MismatchError},
-        matrix::{operations, Matrix},
+        error::{CustomErrors, EmptyVectorError, MismatchError, NotImplementedError},
+        matrix::{logic::approx_eq, operations, Matrix},
+        vector::util::{create_identity_matrix, from_fn},
     };
 
-    use super::{multiply_matrices, multiply_matrix_vector};
+    use super::{
+        abs, add_matrices, argmax_rows, block_matrix, determinant, determinant_lu, diagonal,
+        frobenius_norm, get_determinant, hadamard_product, hstack, kronecker, matrix_power, matvec,
+        max_element, min_element, multiply_matrices, multiply_matrix_vector, norm, outer_product,
+        scalar_multiply, signum, strassen_multiply, subtract_matrices, trace, vstack,
+    };
     #[test]
     fn test_multiply_matrix_vector() {
         let mat_rows = vec![vec![1.0, -1.0, 2.0], vec![0.0, -3.0, 1.0]];
@@ -232,7 +829,7 @@ mod tests {
 
         let vec2 = vec![2.0, 1.0];
 
-        let assumed = CustomErrors::Mismatch(MismatchError);
+        let assumed = CustomErrors::Mismatch(MismatchError::new((3, 1), (2, 1)));
         match multiply_matrix_vector(&mat, &vec2) {
             Ok(_) => {}
             Err(err) => {
@@ -251,7 +848,7 @@ mod tests {
         let m2 = Matrix::new(rows_2).unwrap();
         let rows_3 = vec![vec![10, -2], vec![-4, 8]];
         let m3 = Matrix::new(rows_3).unwrap();
-        match operations::add_matrices(m, m2) {
+        match operations::add_matrices(&m, &m2) {
             Ok(val) => {
                 assert_eq!(val, m3)
             }
@@ -259,6 +856,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_subtract_matrices_2_x_2() {
+        let m = Matrix::new(vec![vec![5, -1], vec![-2, 4]]).unwrap();
+        let m2 = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let m3 = Matrix::new(vec![vec![4, -3], vec![-5, 0]]).unwrap();
+        match subtract_matrices(&m, &m2) {
+            Ok(val) => {
+                assert_eq!(val, m3)
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_subtract_matrices_mismatch() {
+        let m = Matrix::new(vec![vec![5, -1], vec![-2, 4]]).unwrap();
+        let m2 = Matrix::new(vec![vec![1, 2, 3], vec![3, 4, 5]]).unwrap();
+        match subtract_matrices(&m, &m2) {
+            Ok(_) => assert!(false),
+            Err(err) => {
+                assert_eq!(
+                    err,
+                    CustomErrors::Mismatch(MismatchError::new((2, 2), (2, 3)))
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar_multiply() {
+        let m = Matrix::new(vec![vec![1, -2], vec![3, 4]]).unwrap();
+        let target = Matrix::new(vec![vec![3, -6], vec![9, 12]]).unwrap();
+        let scaled = scalar_multiply(&m, 3);
+        assert_eq!(scaled, target);
+        assert_eq!(scaled.m, m.m);
+        assert_eq!(scaled.n, m.n);
+
+        let m = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let target = Matrix::new(vec![vec![0.5, 1.0, 1.5], vec![2.0, 2.5, 3.0]]).unwrap();
+        let scaled = scalar_multiply(&m, 0.5);
+        assert_eq!(scaled, target);
+        assert_eq!(scaled.m, 2);
+        assert_eq!(scaled.n, 3);
+    }
+
+    #[test]
+    fn test_trace() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+        assert_eq!(trace(&m).unwrap(), 15);
+
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        match trace(&m) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+    }
+
     #[test]
     fn test_get_determinant_2_x_2() {
         let rows = vec![vec![5, -1], vec![-2, 4]];
@@ -291,6 +945,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_multiply_matrices_mismatch() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let m2 = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+
+        match multiply_matrices(&m, &m2) {
+            Ok(_) => assert!(false),
+            Err(CustomErrors::Mismatch(err)) => {
+                assert_eq!(err.expected, (3, 2));
+                assert_eq!(err.found, (2, 2));
+            }
+            Err(_) => assert!(false),
+        }
+    }
+
     #[test]
     fn test_get_determinant_3_x_3() {
         let rows = vec![vec![5, 1, -1], vec![-2, 4, 2], vec![3, 3, 3]];
@@ -321,4 +990,487 @@ mod tests {
             _ => assert!(false),
         };
     }
+
+    #[test]
+    fn test_determinant_lu_matches_get_determinant() {
+        let rows: Vec<Vec<f64>> = vec![
+            vec![5.0, 1.0, -1.0, 0.0, 2.0],
+            vec![-2.0, 4.0, 2.0, -1.0, 1.0],
+            vec![3.0, 3.0, 3.0, 3.0, 0.0],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![0.0, -1.0, 2.0, 1.0, 3.0],
+        ];
+        let m = Matrix::new(rows).unwrap();
+
+        let permutation_det = get_determinant(&m).unwrap();
+        let lu_det = determinant_lu(&m).unwrap();
+
+        assert!((permutation_det - lu_det).abs() < 1e-9)
+    }
+
+    #[test]
+    fn test_determinant_lu_50_x_50() {
+        // lower triangular part plus a diagonal of 2s, so the
+        // determinant is 2^50 regardless of pivoting
+        let size = 50;
+        let mut rows: Vec<Vec<f64>> = vec![];
+        for i in 0..size {
+            let mut row: Vec<f64> = vec![];
+            for j in 0..size {
+                if i == j {
+                    row.push(2.0)
+                } else if j < i {
+                    row.push(((i + j) % 3) as f64)
+                } else {
+                    row.push(0.0)
+                }
+            }
+            rows.push(row)
+        }
+        let m = Matrix::new(rows).unwrap();
+
+        let start = std::time::Instant::now();
+        let det = determinant_lu(&m).unwrap();
+        let elapsed = start.elapsed();
+
+        let target: f64 = 2.0_f64.powi(50);
+        assert!(((det - target) / target).abs() < 1e-9);
+        assert!(elapsed.as_secs_f64() < 1.0)
+    }
+
+    #[test]
+    fn test_multiply_matrices_matches_nested_rows() {
+        let a = Matrix::new(vec![
+            vec![1, -2, 3, 0],
+            vec![4, 5, -6, 2],
+            vec![-7, 8, 9, 1],
+        ])
+        .unwrap();
+        let b = Matrix::new(vec![vec![2, 1], vec![0, -3], vec![5, 4], vec![-1, 6]]).unwrap();
+
+        // the straightforward i-j-k product over the nested rows
+        let a_rows = a.rows();
+        let b_rows = b.rows();
+        let mut target_rows: Vec<Vec<i32>> = vec![];
+        for i in 0..a.m {
+            let mut row: Vec<i32> = vec![];
+            for j in 0..b.n {
+                let mut element_sum = 0;
+                for k in 0..a.n {
+                    element_sum += a_rows[i][k] * b_rows[k][j]
+                }
+                row.push(element_sum)
+            }
+            target_rows.push(row)
+        }
+
+        let product = multiply_matrices(&a, &b).unwrap();
+        assert_eq!(product, Matrix::new(target_rows).unwrap());
+    }
+
+    #[test]
+    fn test_multiply_matrices_100_x_100() {
+        let size = 100;
+        let a = from_fn(size, size, |i, j| ((i * 7 + j * 3) % 11) as f64).unwrap();
+        let b = scalar_multiply(&create_identity_matrix::<f64>(size).unwrap(), 2.0);
+
+        let start = std::time::Instant::now();
+        let product = multiply_matrices(&a, &b).unwrap();
+        let elapsed = start.elapsed();
+
+        assert_eq!(product, scalar_multiply(&a, 2.0));
+        assert!(elapsed.as_secs_f64() < 1.0)
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_multiply_matrices_parallel() {
+        let size = 64;
+        let a = from_fn(size, size, |i, j| ((i * 5 + j) % 13) as f64 - 6.0).unwrap();
+        let b = from_fn(size, size, |i, j| ((i + j * 3) % 7) as f64 * 0.5).unwrap();
+
+        let serial = multiply_matrices(&a, &b).unwrap();
+        let parallel = super::multiply_matrices_parallel(&a, &b).unwrap();
+        assert_eq!(parallel, serial);
+
+        let c = from_fn(size, 3, |i, j| (i * j) as f64).unwrap();
+        match super::multiply_matrices_parallel(&c, &a) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 64), (64, 64)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_strassen_multiply() {
+        // below the threshold, exactly the power of two it pads to, a
+        // non-power-of-two size that needs padding and a rectangular product
+        let shapes = vec![(3, 3, 3), (128, 128, 128), (100, 100, 100), (70, 90, 65)];
+        for (m, k, n) in shapes {
+            let a = from_fn(m, k, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0).unwrap();
+            let b = from_fn(k, n, |i, j| ((i + j * 5) % 9) as f64 * 0.25).unwrap();
+
+            let target = multiply_matrices(&a, &b).unwrap();
+            let product = strassen_multiply(&a, &b).unwrap();
+            assert_eq!((product.m, product.n), (m, n));
+            assert!(approx_eq(&product, &target, 1e-9));
+        }
+
+        // skewed shapes fall back to the plain product instead of padding
+        let row = from_fn(1, 100000, |_, j| (j % 3) as f64).unwrap();
+        let product = strassen_multiply(&row, &row.transpose()).unwrap();
+        assert_eq!((product.m, product.n), (1, 1));
+        assert_eq!(product[(0, 0)], 166665.0);
+
+        let a = from_fn(2, 3, |i, j| (i + j) as f64).unwrap();
+        match strassen_multiply(&a, &a) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 3), (2, 3)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let target = Matrix::new(vec![vec![4, 5], vec![8, 10], vec![12, 15]]).unwrap();
+        assert_eq!(outer_product(&vec![1, 2, 3], &vec![4, 5]).unwrap(), target);
+
+        match outer_product(&vec![1, 2, 3], &vec![]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_kronecker() {
+        let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let b = Matrix::new(vec![vec![0, 5], vec![6, 7]]).unwrap();
+        let target = Matrix::new(vec![
+            vec![0, 5, 0, 10],
+            vec![6, 7, 12, 14],
+            vec![0, 15, 0, 20],
+            vec![18, 21, 24, 28],
+        ])
+        .unwrap();
+        assert_eq!(kronecker(&a, &b), target);
+
+        let row = Matrix::new(vec![vec![1, -1]]).unwrap();
+        let col = Matrix::new(vec![vec![2], vec![3]]).unwrap();
+        let target = Matrix::new(vec![vec![2, -2], vec![3, -3]]).unwrap();
+        assert_eq!(kronecker(&row, &col), target);
+    }
+
+    #[test]
+    fn test_operator_overloads() {
+        let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let b = Matrix::new(vec![vec![5, 6], vec![7, 8]]).unwrap();
+
+        let sum = Matrix::new(vec![vec![6, 8], vec![10, 12]]).unwrap();
+        assert_eq!(&a + &b, sum);
+        assert_eq!(a.clone() + b.clone(), sum);
+
+        let difference = Matrix::new(vec![vec![-4, -4], vec![-4, -4]]).unwrap();
+        assert_eq!(&a - &b, difference);
+        assert_eq!(a.clone() - b.clone(), difference);
+
+        let product = Matrix::new(vec![vec![19, 22], vec![43, 50]]).unwrap();
+        assert_eq!(&a * &b, product);
+        assert_eq!(a.clone() * b.clone(), product);
+
+        let scaled = Matrix::new(vec![vec![2, 4], vec![6, 8]]).unwrap();
+        assert_eq!(&a * 2, scaled);
+        assert_eq!(a * 2, scaled);
+    }
+
+    #[test]
+    #[should_panic(expected = "shape mismatch: expected 2x2, found 1x2")]
+    fn test_add_operator_mismatch() {
+        let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let b = Matrix::new(vec![vec![5, 6]]).unwrap();
+        let _ = a + b;
+    }
+
+    #[test]
+    fn test_norms() {
+        let m = Matrix::new(vec![vec![1.0, -2.0], vec![3.0, -4.0]]).unwrap();
+
+        assert_eq!(frobenius_norm(&m), 30.0_f64.sqrt());
+        assert_eq!(norm(&m, 2), frobenius_norm(&m));
+        assert_eq!(norm(&m, 1), 10.0);
+
+        let m = Matrix::new(vec![vec![0.0, 2.0], vec![0.0, -2.0]]).unwrap();
+        assert_eq!(norm(&m, 3), 16.0_f64.powf(1.0 / 3.0));
+        assert_eq!(norm(&m, 0), 2.0);
+    }
+
+    #[test]
+    fn test_operands_usable_after_call() {
+        let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let b = Matrix::new(vec![vec![1, 0], vec![0, 1]]).unwrap();
+
+        let sum = add_matrices(&a, &b).unwrap();
+        let product = multiply_matrices(&a, &b).unwrap();
+
+        assert_eq!(sum.rows(), vec![vec![2, 2], vec![3, 5]]);
+        assert_eq!(product, a);
+        assert_eq!(a.rows(), vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(b.rows(), vec![vec![1, 0], vec![0, 1]]);
+    }
+
+    #[test]
+    fn test_hadamard_product() {
+        let a = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let b = Matrix::new(vec![vec![2, 0, -1], vec![1, 3, 2]]).unwrap();
+        let target = Matrix::new(vec![vec![2, 0, -3], vec![4, 15, 12]]).unwrap();
+
+        assert_eq!(hadamard_product(&a, &b).unwrap(), target);
+
+        let c = Matrix::new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+        match hadamard_product(&a, &c) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 3), (3, 2)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_matrix_power() {
+        let a = Matrix::new(vec![vec![1, 1], vec![1, 0]]).unwrap();
+        let identity = Matrix::new(vec![vec![1, 0], vec![0, 1]]).unwrap();
+
+        assert_eq!(matrix_power(&a, 0).unwrap(), identity);
+        assert_eq!(matrix_power(&a, 1).unwrap(), a);
+
+        let a_2 = multiply_matrices(&a, &a).unwrap();
+        let a_3 = multiply_matrices(&a_2, &a).unwrap();
+        assert_eq!(matrix_power(&a, 3).unwrap(), a_3);
+
+        // fibonacci numbers F(11), F(10), F(9)
+        let a_10 = Matrix::new(vec![vec![89, 55], vec![55, 34]]).unwrap();
+        assert_eq!(matrix_power(&a, 10).unwrap(), a_10);
+
+        let rectangular = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        match matrix_power(&rectangular, 2) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let m = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+        assert_eq!(diagonal(&m).unwrap(), vec![1, 5, 9]);
+
+        let wide = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(diagonal(&wide).unwrap(), vec![1, 5]);
+
+        let tall = wide.transpose();
+        assert_eq!(diagonal(&tall).unwrap(), vec![1, 5]);
+    }
+
+    #[test]
+    fn test_hstack() {
+        let left = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let right = Matrix::new(vec![vec![5], vec![6]]).unwrap();
+        let target = Matrix::new(vec![vec![1, 2, 5], vec![3, 4, 6]]).unwrap();
+
+        assert_eq!(hstack(&left, &right).unwrap(), target);
+
+        let short = Matrix::new(vec![vec![5, 6]]).unwrap();
+        match hstack(&left, &short) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 2), (1, 2)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_vstack() {
+        let top = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let bottom = Matrix::new(vec![vec![5, 6]]).unwrap();
+        let target = Matrix::new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+
+        assert_eq!(vstack(&top, &bottom).unwrap(), target);
+
+        let narrow = Matrix::new(vec![vec![5], vec![6]]).unwrap();
+        match vstack(&top, &narrow) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 2), (2, 1)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_determinant_matches_get_determinant() {
+        let matrices: Vec<Vec<Vec<f64>>> = vec![
+            vec![vec![7.0]],
+            vec![vec![5.0, -1.0], vec![-2.0, 4.0]],
+            vec![
+                vec![5.0, 1.0, -1.0],
+                vec![-2.0, 4.0, 2.0],
+                vec![3.0, 3.0, 3.0],
+            ],
+            vec![
+                vec![5.0, 1.0, -1.0, 0.0],
+                vec![-2.0, 4.0, 2.0, -1.0],
+                vec![3.0, 3.0, 3.0, 3.0],
+                vec![1.0, 2.0, 3.0, 4.0],
+            ],
+        ];
+
+        for rows in matrices {
+            let m = Matrix::new(rows).unwrap();
+            let expected = get_determinant(&m).unwrap();
+            let det = determinant(&m).unwrap();
+            if m.n <= 3 {
+                assert_eq!(det, expected);
+            } else {
+                assert!((det - expected).abs() < 1e-9);
+            }
+        }
+
+        let rect = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        match determinant(&rect) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotImplemented(NotImplementedError)),
+        }
+    }
+
+    #[test]
+    fn test_matvec() {
+        let mat = Matrix::new(vec![vec![1, -1, 2], vec![0, -3, 1]]).unwrap();
+        assert_eq!(matvec(&mat, &vec![2, 1, 0]).unwrap(), vec![1, -3]);
+
+        let mat = Matrix::new(vec![vec![1.0, -1.0, 2.0], vec![0.0, -3.0, 1.0]]).unwrap();
+        let v = vec![0.5, 2.0, -1.0];
+        assert_eq!(
+            matvec(&mat, &v).unwrap(),
+            multiply_matrix_vector(&mat, &v).unwrap()
+        );
+
+        match matvec(&mat, &vec![1.0, 2.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 1), (2, 1)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_block_matrix() {
+        let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let b = Matrix::new(vec![vec![5, 6], vec![7, 8]]).unwrap();
+        let c = Matrix::new(vec![vec![0, 0], vec![0, 0]]).unwrap();
+        let d = Matrix::new(vec![vec![1, 0], vec![0, 1]]).unwrap();
+
+        let target = Matrix::new(vec![
+            vec![1, 2, 5, 6],
+            vec![3, 4, 7, 8],
+            vec![0, 0, 1, 0],
+            vec![0, 0, 0, 1],
+        ])
+        .unwrap();
+        let blocks = vec![vec![a.clone(), b.clone()], vec![c.clone(), d.clone()]];
+        assert_eq!(block_matrix(&blocks).unwrap(), target);
+
+        // blocks only need to agree within their grid row and column
+        let wide = Matrix::new(vec![vec![9, 9, 9]]).unwrap();
+        let single = Matrix::new(vec![vec![1]]).unwrap();
+        let tall = Matrix::new(vec![vec![2, 2, 2], vec![3, 3, 3]]).unwrap();
+        let column = Matrix::new(vec![vec![4], vec![5]]).unwrap();
+        let mixed = block_matrix(&vec![vec![wide, single], vec![tall, column]]).unwrap();
+        assert_eq!(
+            mixed.rows(),
+            vec![vec![9, 9, 9, 1], vec![2, 2, 2, 4], vec![3, 3, 3, 5]]
+        );
+
+        let short = Matrix::new(vec![vec![1, 2]]).unwrap();
+        match block_matrix(&vec![vec![a.clone(), short], vec![c.clone(), d.clone()]]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 2), (1, 2)))
+            ),
+        }
+
+        let narrow = Matrix::new(vec![vec![1], vec![2]]).unwrap();
+        match block_matrix(&vec![vec![a.clone(), b.clone()], vec![narrow, d.clone()]]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 2), (2, 1)))
+            ),
+        }
+
+        let empty: Vec<Vec<Matrix<i32>>> = vec![];
+        match block_matrix(&empty) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_max_min_element() {
+        let m = Matrix::new(vec![
+            vec![3.0, -1.0, 4.0],
+            vec![1.5, 9.0, -2.5],
+            vec![2.0, 6.0, 5.0],
+        ])
+        .unwrap();
+
+        assert_eq!(max_element(&m), Some((1, 1, 9.0)));
+        assert_eq!(min_element(&m), Some((1, 2, -2.5)));
+
+        // ties go to the first position in row-major order
+        let ties = Matrix::new(vec![vec![1.0, 7.0], vec![7.0, 1.0]]).unwrap();
+        assert_eq!(max_element(&ties), Some((0, 1, 7.0)));
+        assert_eq!(min_element(&ties), Some((0, 0, 1.0)));
+
+        let with_nan = Matrix::new(vec![vec![f64::NAN, 2.0], vec![-3.0, 1.0]]).unwrap();
+        assert_eq!(max_element(&with_nan), Some((0, 1, 2.0)));
+        assert_eq!(min_element(&with_nan), Some((1, 0, -3.0)));
+
+        let empty: Matrix<f64> = Matrix::from_rows_unchecked(vec![]);
+        assert_eq!(max_element(&empty), None);
+        assert_eq!(min_element(&empty), None);
+    }
+
+    #[test]
+    fn test_argmax_rows() {
+        let scores = Matrix::new(vec![
+            vec![0.1, 0.7, 0.2],
+            vec![0.9, 0.05, 0.05],
+            vec![0.3, 0.3, 0.4],
+        ])
+        .unwrap();
+        assert_eq!(argmax_rows(&scores), vec![1, 0, 2]);
+
+        let ties = Matrix::new(vec![vec![0.5, 0.5, 0.0], vec![-1.0, 2.0, 2.0]]).unwrap();
+        assert_eq!(argmax_rows(&ties), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_abs_signum() {
+        let m = Matrix::new(vec![vec![-2.5, 0.0, 3.0], vec![4.0, -0.5, -0.0]]).unwrap();
+
+        assert_eq!(
+            abs(&m).rows(),
+            vec![vec![2.5, 0.0, 3.0], vec![4.0, 0.5, 0.0]]
+        );
+        assert_eq!(
+            signum(&m).rows(),
+            vec![vec![-1.0, 0.0, 1.0], vec![1.0, -1.0, 0.0]]
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
     error::CustomErrors,
     matrix::{
-        operations::{multiply_matrices, scalar_multiply, sub_matrices},
+        operations::{multiply_matrices, scalar_multiply, subtract_matrices},
         Matrix,
     },
     vector::util::create_identity_matrix,
@@ -22,14 +22,13 @@ pub fn gen_alpha<T: Copy + From<u8> + Into<f64> + std::cmp::PartialOrd>(
     matrix: &Matrix<T>,
     k: usize,
 ) -> f64 {
-    let rows = &matrix.rows;
     let m = matrix.m;
-    let element = rows[k][k - 1];
+    let element = matrix[(k, k - 1)];
     let sign = get_sign(element);
 
     let mut sum = 0.0;
     for j in k..m {
-        let next: f64 = rows[j][k - 1].into();
+        let next: f64 = matrix[(j, k - 1)].into();
         sum += next.powf(2.0);
     }
 
@@ -37,8 +36,7 @@ pub fn gen_alpha<T: Copy + From<u8> + Into<f64> + std::cmp::PartialOrd>(
 }
 
 pub fn get_r<T: Copy + Into<f64>>(matrix: &Matrix<T>, alpha: f64, k: usize) -> f64 {
-    let rows = &matrix.rows;
-    let element: f64 = rows[k][k - 1].into();
+    let element: f64 = matrix[(k, k - 1)].into();
     let inner_term = 0.5 * (alpha.powf(2.0) - (element * alpha));
     inner_term.powf(0.5)
 }
@@ -49,7 +47,6 @@ pub fn construct_hh_vector<T: Copy + Into<f64>>(
     r: f64,
     k: usize,
 ) -> Vec<f64> {
-    let rows = &matrix.rows;
     let m = matrix.m;
 
     let mut hh_vector: Vec<f64> = vec![];
@@ -60,13 +57,13 @@ pub fn construct_hh_vector<T: Copy + Into<f64>>(
         }
 
         if j == k {
-            let e2: f64 = rows[k][k - 1].into();
+            let e2: f64 = matrix[(k, k - 1)].into();
             let v2 = (e2 - alpha) / (2.0 * r);
             hh_vector.push(v2);
             continue;
         }
 
-        let ej: f64 = rows[j][k - 1].into();
+        let ej: f64 = matrix[(j, k - 1)].into();
         hh_vector.push(ej / (2.0 * r))
     }
 
@@ -102,17 +99,14 @@ pub fn get_house_holder_matrix<
         Err(err) => return Err(err),
     };
 
-    let scaled_v_prod = match scalar_multiply(&v_prod, 2.0) {
-        Ok(scaled_v_prd) => scaled_v_prd,
-        Err(err) => return Err(err),
-    };
+    let scaled_v_prod = scalar_multiply(&v_prod, 2.0);
 
     let identity_matrix = match create_identity_matrix(matrix.m) {
         Ok(identity_matrix) => identity_matrix,
         Err(err) => return Err(err),
     };
 
-    sub_matrices(identity_matrix, scaled_v_prod)
+    subtract_matrices(&identity_matrix, &scaled_v_prod)
 }
 
 pub fn house_holder_transform<
@@ -1,3 +1,4 @@
 pub mod householder;
+pub mod rotation;
 pub mod scaling;
 pub mod tridiagonalize;
@@ -0,0 +1,79 @@
+use crate::matrix::Matrix;
+
+// counter-clockwise rotation by theta radians
+pub fn rotation_2d(theta: f64) -> Matrix<f64> {
+    let (s, c) = theta.sin_cos();
+    Matrix {
+        data: vec![c, -s, s, c],
+        m: 2,
+        n: 2,
+    }
+}
+
+pub fn rotation_3d_x(theta: f64) -> Matrix<f64> {
+    let (s, c) = theta.sin_cos();
+    Matrix {
+        data: vec![1.0, 0.0, 0.0, 0.0, c, -s, 0.0, s, c],
+        m: 3,
+        n: 3,
+    }
+}
+
+pub fn rotation_3d_y(theta: f64) -> Matrix<f64> {
+    let (s, c) = theta.sin_cos();
+    Matrix {
+        data: vec![c, 0.0, s, 0.0, 1.0, 0.0, -s, 0.0, c],
+        m: 3,
+        n: 3,
+    }
+}
+
+pub fn rotation_3d_z(theta: f64) -> Matrix<f64> {
+    let (s, c) = theta.sin_cos();
+    Matrix {
+        data: vec![c, -s, 0.0, s, c, 0.0, 0.0, 0.0, 1.0],
+        m: 3,
+        n: 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::matrix::logic::is_orthogonal;
+    use crate::matrix::operations::{determinant, multiply_matrix_vector};
+
+    use super::{rotation_2d, rotation_3d_x, rotation_3d_y, rotation_3d_z};
+
+    #[test]
+    fn test_rotation_2d() {
+        let rotated = multiply_matrix_vector(&rotation_2d(PI / 2.0), &vec![1.0, 0.0]).unwrap();
+        assert!(rotated[0].abs() < 1e-12);
+        assert!((rotated[1] - 1.0).abs() < 1e-12);
+
+        assert!(is_orthogonal(&rotation_2d(0.7), 1e-12));
+    }
+
+    #[test]
+    fn test_rotation_3d() {
+        for theta in [0.0, 0.3, PI / 2.0, 2.5, -1.2] {
+            for rotation in [
+                rotation_3d_x(theta),
+                rotation_3d_y(theta),
+                rotation_3d_z(theta),
+            ] {
+                assert!(is_orthogonal(&rotation, 1e-12));
+                assert!((determinant(&rotation).unwrap() - 1.0).abs() < 1e-12);
+            }
+        }
+
+        // right handed, x goes to y about z, y goes to z about x, z goes to x about y
+        let v = multiply_matrix_vector(&rotation_3d_z(PI / 2.0), &vec![1.0, 0.0, 0.0]).unwrap();
+        assert!((v[1] - 1.0).abs() < 1e-12);
+        let v = multiply_matrix_vector(&rotation_3d_x(PI / 2.0), &vec![0.0, 1.0, 0.0]).unwrap();
+        assert!((v[2] - 1.0).abs() < 1e-12);
+        let v = multiply_matrix_vector(&rotation_3d_y(PI / 2.0), &vec![0.0, 0.0, 1.0]).unwrap();
+        assert!((v[0] - 1.0).abs() < 1e-12);
+    }
+}
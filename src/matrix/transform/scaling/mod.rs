@@ -16,13 +16,12 @@ pub fn standardize<
 ) -> Result<Matrix<f64>, CustomErrors> {
     let m = matrix.m;
     let n = matrix.n;
-    let rows = &matrix.rows;
     let mut new_rows = vec![];
     for j in 0..n {
         let mut new_row = vec![];
         let mut features: Vec<f64> = vec![];
         for i in 0..m {
-            let v: f64 = rows[i][j].into();
+            let v: f64 = matrix[(i, j)].into();
             features.push(v)
         }
 
@@ -57,36 +57,33 @@ mod tests {
         let matrix = Matrix::new(rows_1).unwrap();
 
         let calculated_tridiagonalized = tridiagonalize(&matrix).unwrap();
-        let target_tridiagonalized = Matrix {
-            rows: vec![
-                vec![
-                    4.0,
-                    -3.000000000000001,
-                    1.332267629550197e-16,
-                    -9.325873406851313e-16,
-                ],
-                vec![
-                    -3.000000000000001,
-                    3.3333333333333357,
-                    -1.666666666666667,
-                    -2.220446049250313e-16,
-                ],
-                vec![
-                    1.332267629550197e-16,
-                    -1.6666666666666665,
-                    -1.3200000000000016,
-                    0.9066666666666631,
-                ],
-                vec![
-                    -9.325873406851313e-16,
-                    0.0,
-                    0.9066666666666627,
-                    1.986666666666669,
-                ],
+        let target_tridiagonalized = Matrix::new(vec![
+            vec![
+                4.0,
+                -3.000000000000001,
+                1.332267629550197e-16,
+                -9.325873406851313e-16,
             ],
-            m: 4,
-            n: 4,
-        };
+            vec![
+                -3.000000000000001,
+                3.3333333333333357,
+                -1.666666666666667,
+                -2.220446049250313e-16,
+            ],
+            vec![
+                1.332267629550197e-16,
+                -1.6666666666666665,
+                -1.3200000000000016,
+                0.9066666666666631,
+            ],
+            vec![
+                -9.325873406851313e-16,
+                0.0,
+                0.9066666666666627,
+                1.986666666666669,
+            ],
+        ])
+        .unwrap();
 
         assert_eq!(calculated_tridiagonalized, target_tridiagonalized)
     }
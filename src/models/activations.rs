@@ -0,0 +1,169 @@
+use crate::matrix::Matrix;
+
+pub fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+pub fn sigmoid_derivative(x: f64) -> f64 {
+    let s = sigmoid(x);
+    s * (1.0 - s)
+}
+
+pub fn relu(x: f64) -> f64 {
+    if x > 0.0 {
+        x
+    } else {
+        0.0
+    }
+}
+
+// the kink at zero is given a slope of 0
+pub fn relu_derivative(x: f64) -> f64 {
+    if x > 0.0 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+pub fn tanh_activation(x: f64) -> f64 {
+    x.tanh()
+}
+
+pub fn tanh_derivative(x: f64) -> f64 {
+    1.0 - x.tanh().powi(2)
+}
+
+pub fn sigmoid_matrix(m: &Matrix<f64>) -> Matrix<f64> {
+    m.map(sigmoid)
+}
+
+pub fn relu_matrix(m: &Matrix<f64>) -> Matrix<f64> {
+    m.map(relu)
+}
+
+pub fn tanh_matrix(m: &Matrix<f64>) -> Matrix<f64> {
+    m.map(tanh_activation)
+}
+
+// the max is subtracted before exponentiating so large inputs don't overflow
+pub fn softmax(v: &Vec<f64>) -> Vec<f64> {
+    let mut max = f64::NEG_INFINITY;
+    for i in 0..v.len() {
+        if v[i] > max {
+            max = v[i]
+        }
+    }
+
+    let mut exps: Vec<f64> = vec![];
+    let mut sum = 0.0;
+    for i in 0..v.len() {
+        let e = (v[i] - max).exp();
+        sum += e;
+        exps.push(e)
+    }
+
+    for i in 0..exps.len() {
+        exps[i] /= sum
+    }
+    exps
+}
+
+pub fn softmax_rows(m: &Matrix<f64>) -> Matrix<f64> {
+    let mut new_data: Vec<f64> = Vec::with_capacity(m.data.len());
+    for row in m.iter_rows() {
+        new_data.extend(softmax(&row.to_vec()))
+    }
+
+    Matrix {
+        data: new_data,
+        m: m.m,
+        n: m.n,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    use super::{
+        relu, relu_derivative, relu_matrix, sigmoid, sigmoid_derivative, sigmoid_matrix, softmax,
+        softmax_rows, tanh_activation, tanh_derivative, tanh_matrix,
+    };
+
+    #[test]
+    fn test_activations() {
+        assert_eq!(sigmoid(0.0), 0.5);
+        assert!(sigmoid(20.0) > 0.999);
+        assert!(sigmoid(-20.0) < 0.001);
+
+        assert_eq!(relu(-1.0), 0.0);
+        assert_eq!(relu(2.5), 2.5);
+
+        assert_eq!(tanh_activation(0.0), 0.0);
+        assert!((tanh_activation(1.0) - 0.7615941559557649).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_activation_derivatives() {
+        assert_eq!(sigmoid_derivative(0.0), 0.25);
+        assert_eq!(relu_derivative(-1.0), 0.0);
+        assert_eq!(relu_derivative(0.0), 0.0);
+        assert_eq!(relu_derivative(3.0), 1.0);
+        assert_eq!(tanh_derivative(0.0), 1.0);
+
+        // compare against a central difference
+        let h = 1e-6;
+        for x in [-2.0, -0.5, 0.3, 1.7] {
+            let numeric = (sigmoid(x + h) - sigmoid(x - h)) / (2.0 * h);
+            assert!((sigmoid_derivative(x) - numeric).abs() < 1e-8);
+
+            let numeric = (tanh_activation(x + h) - tanh_activation(x - h)) / (2.0 * h);
+            assert!((tanh_derivative(x) - numeric).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_activation_matrices() {
+        let m = Matrix::new(vec![vec![0.0, -1.0], vec![2.0, -3.0]]).unwrap();
+
+        assert_eq!(
+            sigmoid_matrix(&m).rows(),
+            vec![vec![0.5, sigmoid(-1.0)], vec![sigmoid(2.0), sigmoid(-3.0)]]
+        );
+        assert_eq!(relu_matrix(&m).rows(), vec![vec![0.0, 0.0], vec![2.0, 0.0]]);
+        assert_eq!(
+            tanh_matrix(&m).rows(),
+            vec![
+                vec![0.0, (-1.0f64).tanh()],
+                vec![2.0f64.tanh(), (-3.0f64).tanh()]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_softmax() {
+        let s = softmax(&vec![1.0, 2.0, 3.0]);
+        assert!((s.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(s[0] < s[1] && s[1] < s[2]);
+        assert!((s[2] - 0.6652409557748219).abs() < 1e-12);
+
+        let s = softmax(&vec![1000.0, 1001.0, 1002.0]);
+        assert!(s.iter().all(|v| v.is_finite()));
+        assert!((s[2] - 0.6652409557748219).abs() < 1e-12);
+
+        assert_eq!(softmax(&vec![]), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn test_softmax_rows() {
+        let m = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![0.0, 0.0, 0.0]]).unwrap();
+        let s = softmax_rows(&m);
+
+        assert_eq!(s.row(0).unwrap(), softmax(&vec![1.0, 2.0, 3.0]));
+        for row in s.iter_rows() {
+            assert!((row.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        }
+        assert!((s[(1, 0)] - 1.0 / 3.0).abs() < 1e-12);
+    }
+}
@@ -1,8 +1,13 @@
-// use crate::matrix::Matrix;
+use std::collections::HashMap;
+
+use crate::error::{CustomErrors, EmptyVectorError, MismatchError, NotFittedError};
+use crate::matrix::Matrix;
 
 pub struct DecisionTree {
     _min_samples: u32,
     _max_depth: u32,
+    _n_features: usize,
+    _root: TreeNodes,
 }
 
 pub struct TreeNode {
@@ -14,9 +19,419 @@ pub struct TreeNode {
     _value: u32,
 }
 
-pub struct NullNode {}
-
 pub enum TreeNodes {
-    TreeNode,
-    NullNode,
+    Node(Box<TreeNode>),
+    Null,
+}
+
+impl DecisionTree {
+    pub fn new(min_samples: u32, max_depth: u32) -> DecisionTree {
+        DecisionTree {
+            _min_samples: min_samples,
+            _max_depth: max_depth,
+            _n_features: 0,
+            _root: TreeNodes::Null,
+        }
+    }
+
+    pub fn fit(&mut self, features: &Matrix<f64>, labels: &Vec<u32>) -> Result<(), CustomErrors> {
+        if features.m == 0 || features.n == 0 {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        if labels.len() != features.m {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (features.m, 1),
+                (labels.len(), 1),
+            )));
+        }
+
+        let samples: Vec<usize> = (0..features.m).collect();
+        self._n_features = features.n;
+        self._root = self.build_node(features, labels, &samples, 0);
+        Ok(())
+    }
+
+    pub fn predict(&self, features: &Matrix<f64>) -> Result<Vec<u32>, CustomErrors> {
+        let root = match &self._root {
+            TreeNodes::Node(root) => root,
+            TreeNodes::Null => return Err(CustomErrors::NotFitted(NotFittedError)),
+        };
+
+        if features.n != self._n_features {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (features.m, self._n_features),
+                (features.m, features.n),
+            )));
+        }
+
+        let mut predictions: Vec<u32> = vec![];
+        for i in 0..features.m {
+            let mut node = root;
+            // leaves have no children, internal nodes always have both
+            loop {
+                let next = if features[(i, node._feature as usize)] <= node._threshold {
+                    &node._left
+                } else {
+                    &node._right
+                };
+                match next {
+                    TreeNodes::Node(child) => node = child,
+                    TreeNodes::Null => break,
+                }
+            }
+            predictions.push(node._value)
+        }
+
+        Ok(predictions)
+    }
+
+    fn build_node(
+        &self,
+        features: &Matrix<f64>,
+        labels: &Vec<u32>,
+        samples: &Vec<usize>,
+        depth: u32,
+    ) -> TreeNodes {
+        let mut node_labels: Vec<u32> = vec![];
+        for i in 0..samples.len() {
+            node_labels.push(labels[samples[i]])
+        }
+        let value = majority_label(&node_labels);
+        let parent_entropy = entropy(&node_labels);
+
+        let leaf = TreeNode {
+            _feature: 0,
+            _threshold: 0.0,
+            _left: TreeNodes::Null,
+            _right: TreeNodes::Null,
+            _gain: 0.0,
+            _value: value,
+        };
+
+        if depth >= self._max_depth
+            || samples.len() < self._min_samples as usize
+            || parent_entropy == 0.0
+        {
+            return TreeNodes::Node(Box::new(leaf));
+        }
+
+        // try a threshold halfway between every pair of neighbouring values
+        // of every feature, keeping the split with the largest information gain
+        let mut best_gain = 0.0;
+        let mut best_feature = 0;
+        let mut best_threshold = 0.0;
+        for j in 0..features.n {
+            let mut values: Vec<f64> = vec![];
+            for i in 0..samples.len() {
+                values.push(features[(samples[i], j)])
+            }
+            // total_cmp so a NaN feature sorts last instead of panicking
+            values.sort_by(|a, b| a.total_cmp(b));
+            values.dedup();
+
+            for k in 1..values.len() {
+                let threshold = (values[k - 1] + values[k]) / 2.0;
+                let (left_labels, right_labels) =
+                    split_labels(features, labels, samples, j, threshold);
+
+                let total = samples.len() as f64;
+                let left_weight = left_labels.len() as f64 / total;
+                let right_weight = right_labels.len() as f64 / total;
+                let gain = parent_entropy
+                    - left_weight * entropy(&left_labels)
+                    - right_weight * entropy(&right_labels);
+
+                if gain > best_gain {
+                    best_gain = gain;
+                    best_feature = j;
+                    best_threshold = threshold;
+                }
+            }
+        }
+
+        if best_gain <= 0.0 {
+            return TreeNodes::Node(Box::new(leaf));
+        }
+
+        let mut left_samples: Vec<usize> = vec![];
+        let mut right_samples: Vec<usize> = vec![];
+        for i in 0..samples.len() {
+            if features[(samples[i], best_feature)] <= best_threshold {
+                left_samples.push(samples[i])
+            } else {
+                right_samples.push(samples[i])
+            }
+        }
+
+        TreeNodes::Node(Box::new(TreeNode {
+            _feature: best_feature as u32,
+            _threshold: best_threshold,
+            _left: self.build_node(features, labels, &left_samples, depth + 1),
+            _right: self.build_node(features, labels, &right_samples, depth + 1),
+            _gain: best_gain,
+            _value: value,
+        }))
+    }
+}
+
+fn split_labels(
+    features: &Matrix<f64>,
+    labels: &Vec<u32>,
+    samples: &Vec<usize>,
+    feature: usize,
+    threshold: f64,
+) -> (Vec<u32>, Vec<u32>) {
+    let mut left_labels: Vec<u32> = vec![];
+    let mut right_labels: Vec<u32> = vec![];
+    for i in 0..samples.len() {
+        if features[(samples[i], feature)] <= threshold {
+            left_labels.push(labels[samples[i]])
+        } else {
+            right_labels.push(labels[samples[i]])
+        }
+    }
+    (left_labels, right_labels)
+}
+
+fn label_counts(labels: &[u32]) -> HashMap<u32, usize> {
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    for label in labels {
+        *counts.entry(*label).or_insert(0) += 1
+    }
+    counts
+}
+
+// most common label, ties go to the smaller label so fitting is deterministic
+fn majority_label(labels: &[u32]) -> u32 {
+    let counts = label_counts(labels);
+    let mut best_label = 0;
+    let mut best_count = 0;
+    for (label, count) in counts {
+        if count > best_count || (count == best_count && label < best_label) {
+            best_label = label;
+            best_count = count;
+        }
+    }
+    best_label
+}
+
+pub fn gini(labels: &[u32]) -> f64 {
+    let total = labels.len() as f64;
+    let mut gini = 1.0;
+    for (_, count) in label_counts(labels) {
+        let p = count as f64 / total;
+        gini -= p * p
+    }
+    gini
+}
+
+// classes that never appear contribute nothing, i.e. 0 * log2(0) is taken as 0
+pub fn entropy(labels: &[u32]) -> f64 {
+    let total = labels.len() as f64;
+    let mut entropy = 0.0;
+    for (_, count) in label_counts(labels) {
+        let p = count as f64 / total;
+        entropy -= p * p.log2()
+    }
+    entropy
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, MismatchError, NotFittedError};
+    use crate::matrix::Matrix;
+
+    use super::{entropy, gini, DecisionTree, TreeNode, TreeNodes};
+
+    #[test]
+    fn test_gini() {
+        assert_eq!(gini(&[1, 1, 1, 1]), 0.0);
+        assert_eq!(gini(&[0, 1, 0, 1]), 0.5);
+        assert!((gini(&[0, 1, 2]) - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_entropy() {
+        assert_eq!(entropy(&[1, 1, 1, 1]), 0.0);
+        assert_eq!(entropy(&[0, 1, 0, 1]), 1.0);
+        assert!((entropy(&[0, 1, 2, 3]) - 2.0).abs() < 1e-12);
+    }
+
+    fn leaf(value: u32) -> TreeNodes {
+        TreeNodes::Node(Box::new(TreeNode {
+            _feature: 0,
+            _threshold: 0.0,
+            _left: TreeNodes::Null,
+            _right: TreeNodes::Null,
+            _gain: 0.0,
+            _value: value,
+        }))
+    }
+
+    #[test]
+    fn test_hand_built_tree() {
+        // x0 <= 2.5 ? (x1 <= 1.0 ? 0 : 1) : 2
+        let left = TreeNodes::Node(Box::new(TreeNode {
+            _feature: 1,
+            _threshold: 1.0,
+            _left: leaf(0),
+            _right: leaf(1),
+            _gain: 1.0,
+            _value: 0,
+        }));
+        let root = TreeNodes::Node(Box::new(TreeNode {
+            _feature: 0,
+            _threshold: 2.5,
+            _left: left,
+            _right: leaf(2),
+            _gain: 0.9,
+            _value: 0,
+        }));
+
+        // walk the left-most path by hand
+        let mut depth = 0;
+        let mut node = &root;
+        while let TreeNodes::Node(inner) = node {
+            depth += 1;
+            node = &inner._left;
+        }
+        assert_eq!(depth, 3);
+
+        let tree = DecisionTree {
+            _min_samples: 1,
+            _max_depth: 2,
+            _n_features: 2,
+            _root: root,
+        };
+        let features = Matrix::new(vec![
+            vec![1.0, 0.5],
+            vec![2.0, 3.0],
+            vec![2.5, 1.0],
+            vec![4.0, 0.0],
+        ])
+        .unwrap();
+        assert_eq!(tree.predict(&features).unwrap(), vec![0, 1, 0, 2]);
+    }
+
+    #[test]
+    fn test_fit() {
+        let features = Matrix::new(vec![
+            vec![1.0, 1.0],
+            vec![2.0, 1.0],
+            vec![1.0, 2.0],
+            vec![5.0, 5.0],
+            vec![6.0, 5.0],
+            vec![5.0, 6.0],
+        ])
+        .unwrap();
+        let labels = vec![0, 0, 0, 1, 1, 1];
+
+        let mut tree = DecisionTree::new(2, 5);
+        tree.fit(&features, &labels).unwrap();
+
+        let root = match &tree._root {
+            TreeNodes::Node(node) => node,
+            TreeNodes::Null => panic!("root should be a node after fitting"),
+        };
+        assert!((root._gain - 1.0).abs() < 1e-12);
+        assert!(root._threshold > 2.0 && root._threshold < 5.0);
+
+        // one split separates the classes, both children are pure leaves
+        match (&root._left, &root._right) {
+            (TreeNodes::Node(left), TreeNodes::Node(right)) => {
+                assert_eq!(left._value, 0);
+                assert_eq!(right._value, 1);
+                assert!(matches!(left._left, TreeNodes::Null));
+                assert!(matches!(right._right, TreeNodes::Null));
+            }
+            _ => panic!("root should have two children"),
+        }
+    }
+
+    #[test]
+    fn test_fit_max_depth() {
+        let features = Matrix::new(vec![vec![1.0], vec![2.0], vec![3.0], vec![4.0]]).unwrap();
+        let labels = vec![0, 1, 0, 1];
+
+        let mut tree = DecisionTree::new(1, 0);
+        tree.fit(&features, &labels).unwrap();
+
+        match &tree._root {
+            TreeNodes::Node(node) => assert!(matches!(node._left, TreeNodes::Null)),
+            TreeNodes::Null => panic!("root should be a node after fitting"),
+        }
+    }
+
+    #[test]
+    fn test_predict() {
+        let features = Matrix::new(vec![
+            vec![1.0, 1.0],
+            vec![2.0, 1.5],
+            vec![1.5, 2.0],
+            vec![1.0, 6.0],
+            vec![2.0, 5.0],
+            vec![1.5, 5.5],
+            vec![6.0, 5.0],
+            vec![5.0, 6.0],
+            vec![5.5, 5.5],
+        ])
+        .unwrap();
+        let labels = vec![0, 0, 0, 1, 1, 1, 2, 2, 2];
+
+        let mut tree = DecisionTree::new(2, 5);
+        tree.fit(&features, &labels).unwrap();
+
+        let held_out = Matrix::new(vec![
+            vec![0.5, 0.5],
+            vec![1.8, 5.8],
+            vec![6.5, 6.5],
+            vec![2.2, 1.2],
+        ])
+        .unwrap();
+        assert_eq!(tree.predict(&held_out).unwrap(), vec![0, 1, 2, 0]);
+
+        match tree.predict(&Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap()) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((1, 2), (1, 3)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_predict_before_fit() {
+        let tree = DecisionTree::new(2, 5);
+        let features = Matrix::new(vec![vec![1.0, 2.0]]).unwrap();
+
+        match tree.predict(&features) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotFitted(NotFittedError)),
+        }
+    }
+
+    #[test]
+    fn test_fit_label_mismatch() {
+        let features = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let mut tree = DecisionTree::new(2, 3);
+
+        match tree.fit(&features, &vec![0, 1, 1]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (3, 1)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_fit_with_nan_feature() {
+        let features = Matrix::new(vec![vec![1.0], vec![f64::NAN], vec![3.0], vec![4.0]]).unwrap();
+        let mut tree = DecisionTree::new(1, 3);
+        tree.fit(&features, &vec![0, 1, 1, 1]).unwrap();
+
+        let finite = Matrix::new(vec![vec![1.0], vec![4.0]]).unwrap();
+        assert_eq!(tree.predict(&finite).unwrap(), vec![0, 1]);
+    }
 }
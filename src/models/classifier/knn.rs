@@ -0,0 +1,162 @@
+use crate::error::{
+    CustomErrors, EmptyVectorError, InvalidParameterError, MismatchError, NotFittedError,
+};
+use crate::matrix::Matrix;
+
+pub struct KNearestNeighbors {
+    pub k: usize,
+    _x: Vec<Vec<f64>>,
+    _y: Vec<u32>,
+}
+
+impl KNearestNeighbors {
+    pub fn new(k: usize) -> KNearestNeighbors {
+        KNearestNeighbors {
+            k,
+            _x: vec![],
+            _y: vec![],
+        }
+    }
+
+    pub fn fit(&mut self, x: &Matrix<f64>, y: &Vec<u32>) -> Result<(), CustomErrors> {
+        if x.m == 0 || x.n == 0 {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        if y.len() != x.m {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (x.m, 1),
+                (y.len(), 1),
+            )));
+        }
+
+        if self.k == 0 || self.k > x.m {
+            return Err(CustomErrors::InvalidParameter(InvalidParameterError));
+        }
+
+        self._x = x.rows();
+        self._y = y.clone();
+        Ok(())
+    }
+
+    pub fn predict(&self, x: &Matrix<f64>) -> Result<Vec<u32>, CustomErrors> {
+        if self._x.is_empty() {
+            return Err(CustomErrors::NotFitted(NotFittedError));
+        }
+
+        let n_features = self._x[0].len();
+        if x.n != n_features {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (x.m, n_features),
+                (x.m, x.n),
+            )));
+        }
+
+        let mut predictions = vec![];
+        for query in x.iter_rows() {
+            // squared distances rank the same as euclidean ones
+            let mut distances: Vec<(f64, u32)> = vec![];
+            for i in 0..self._x.len() {
+                let mut distance = 0.0;
+                for j in 0..n_features {
+                    distance += (self._x[i][j] - query[j]).powi(2)
+                }
+                distances.push((distance, self._y[i]))
+            }
+            distances.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut votes: Vec<(u32, usize)> = vec![];
+            for &(_, label) in &distances[..self.k] {
+                match votes.iter_mut().find(|vote| vote.0 == label) {
+                    Some(vote) => vote.1 += 1,
+                    None => votes.push((label, 1)),
+                }
+            }
+
+            // ties go to the smallest label
+            let mut best = votes[0];
+            for &vote in &votes[1..] {
+                if vote.1 > best.1 || (vote.1 == best.1 && vote.0 < best.0) {
+                    best = vote
+                }
+            }
+            predictions.push(best.0)
+        }
+
+        Ok(predictions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, InvalidParameterError, MismatchError, NotFittedError};
+    use crate::matrix::Matrix;
+
+    use super::KNearestNeighbors;
+
+    #[test]
+    fn test_knn_predict() {
+        let x = Matrix::new(vec![
+            vec![0.0, 0.0],
+            vec![0.5, 0.0],
+            vec![0.0, 0.5],
+            vec![10.0, 10.0],
+            vec![10.5, 10.0],
+            vec![10.0, 10.5],
+        ])
+        .unwrap();
+        let y = vec![0, 0, 0, 1, 1, 1];
+
+        let mut knn = KNearestNeighbors::new(3);
+        knn.fit(&x, &y).unwrap();
+
+        let queries = Matrix::new(vec![vec![0.2, 0.2], vec![9.8, 10.1], vec![1.0, -1.0]]).unwrap();
+        assert_eq!(knn.predict(&queries).unwrap(), vec![0, 1, 0]);
+    }
+
+    #[test]
+    fn test_knn_tie_break() {
+        let x = Matrix::new(vec![vec![-1.0], vec![1.0]]).unwrap();
+        let mut knn = KNearestNeighbors::new(2);
+        knn.fit(&x, &vec![5, 2]).unwrap();
+
+        let queries = Matrix::new(vec![vec![0.0], vec![-3.0]]).unwrap();
+        assert_eq!(knn.predict(&queries).unwrap(), vec![2, 2]);
+    }
+
+    #[test]
+    fn test_knn_errors() {
+        let x = Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap();
+
+        let knn = KNearestNeighbors::new(1);
+        match knn.predict(&x) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotFitted(NotFittedError)),
+        }
+
+        let mut knn = KNearestNeighbors::new(3);
+        match knn.fit(&x, &vec![0, 1]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::InvalidParameter(InvalidParameterError)),
+        }
+
+        let mut knn = KNearestNeighbors::new(1);
+        match knn.fit(&x, &vec![0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (1, 1)))
+            ),
+        }
+
+        knn.fit(&x, &vec![0, 1]).unwrap();
+        let wrong = Matrix::new(vec![vec![0.0, 1.0, 2.0]]).unwrap();
+        match knn.predict(&wrong) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((1, 2), (1, 3)))
+            ),
+        }
+    }
+}
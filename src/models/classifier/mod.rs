@@ -1,2 +1,3 @@
 pub mod dectree;
+pub mod knn;
 pub mod logreg;
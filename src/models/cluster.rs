@@ -1,8 +1,9 @@
 use core::f64;
 use rand::prelude::*;
+use rand::rngs::StdRng;
 
 use crate::{
-    error::{CustomErrors, MismatchError},
+    error::{CustomErrors, EmptyVectorError, InvalidParameterError, MismatchError, NotFittedError},
     matrix::Matrix,
     vector::operations::{add_vec, scalar_divide},
 };
@@ -18,7 +19,10 @@ pub fn cartesian_distance(p1: &Vec<f64>, p2: &Vec<f64>) -> Result<f64, CustomErr
     let mut sqsum = 0.0;
 
     if p1.len() != p2.len() {
-        return Err(CustomErrors::Mismatch(MismatchError));
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (p1.len(), 1),
+            (p2.len(), 1),
+        )));
     };
 
     for i in 0..p1.len() {
@@ -33,7 +37,7 @@ pub fn cartesian_distance(p1: &Vec<f64>, p2: &Vec<f64>) -> Result<f64, CustomErr
 pub fn kpp_init(data: &Matrix<f64>, _n_centroids: i32) -> Result<Vec<Vec<f64>>, CustomErrors> {
     let mut centroids: Vec<Vec<f64>> = vec![];
     let mut rng = thread_rng();
-    let rows = &data.rows;
+    let rows = data.rows();
     let m = data.m;
     let y = rng.gen_range(0..m);
     centroids.push(rows[y].to_owned());
@@ -49,7 +53,7 @@ pub fn kpp_init(data: &Matrix<f64>, _n_centroids: i32) -> Result<Vec<Vec<f64>>,
                 let centroid = &centroids[j];
                 let dist = match cartesian_distance(pt, centroid) {
                     Ok(val) => val,
-                    Err(_) => return Err(CustomErrors::Mismatch(MismatchError)),
+                    Err(err) => return Err(err),
                 };
                 d = f64::min(d, dist)
             }
@@ -93,7 +97,7 @@ pub fn new_centroids(clusters: &Vec<Vec<Vec<f64>>>) -> Result<Vec<Vec<f64>>, Cus
             } else {
                 new_centroid = match add_vec(&new_centroid, &c[idx]) {
                     Ok(val) => val.to_vec(),
-                    Err(_) => return Err(CustomErrors::Mismatch(MismatchError)),
+                    Err(err) => return Err(err),
                 }
             }
             scalar += 1.0;
@@ -111,21 +115,22 @@ pub fn new_centroids(clusters: &Vec<Vec<Vec<f64>>>) -> Result<Vec<Vec<f64>>, Cus
 pub fn kmeans(data: &Matrix<f64>, n_centroids: i32) -> Result<ClusterResult, CustomErrors> {
     let mut centroids: Vec<Vec<f64>> = match kpp_init(data, n_centroids) {
         Ok(centroids) => centroids,
-        Err(_) => return Err(CustomErrors::Mismatch(MismatchError)),
+        Err(err) => return Err(err),
     };
+    let rows = data.rows();
     let mut clusters: Vec<Vec<Vec<f64>>> = vec![];
     let mut converged: bool = false;
     while !converged {
         clusters = init_clusters(n_centroids);
 
-        for pt in &data.rows {
+        for pt in &rows {
             let mut min_dist = f64::MAX;
             let mut min_idx: usize = 0;
 
             for c in 0..centroids.len() {
                 let dist = match cartesian_distance(&pt, &centroids[c]) {
                     Ok(dist) => dist,
-                    Err(_) => return Err(CustomErrors::Mismatch(MismatchError)),
+                    Err(err) => return Err(err),
                 };
 
                 if dist < min_dist {
@@ -155,14 +160,121 @@ pub fn kmeans(data: &Matrix<f64>, n_centroids: i32) -> Result<ClusterResult, Cus
     })
 }
 
+pub struct KMeans {
+    pub centroids: Vec<Vec<f64>>,
+    pub n_iter: usize,
+}
+
+impl KMeans {
+    pub fn new() -> KMeans {
+        KMeans {
+            centroids: vec![],
+            n_iter: 0,
+        }
+    }
+
+    pub fn fit(
+        &mut self,
+        x: &Matrix<f64>,
+        k: usize,
+        max_iters: usize,
+        seed: u64,
+    ) -> Result<(), CustomErrors> {
+        if x.m == 0 || x.n == 0 {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        if k == 0 || k > x.m {
+            return Err(CustomErrors::InvalidParameter(InvalidParameterError));
+        }
+
+        // start from k distinct rows picked by the seeded rng
+        let rows = x.rows();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut centroids: Vec<Vec<f64>> = vec![];
+        for idx in rand::seq::index::sample(&mut rng, x.m, k) {
+            centroids.push(rows[idx].to_vec())
+        }
+
+        let mut n_iter = 0;
+        while n_iter < max_iters {
+            n_iter += 1;
+
+            let mut sums: Vec<Vec<f64>> = vec![vec![0.0; x.n]; k];
+            let mut counts: Vec<usize> = vec![0; k];
+            for pt in &rows {
+                let c = match nearest_centroid(pt, &centroids) {
+                    Ok(c) => c,
+                    Err(err) => return Err(err),
+                };
+                for j in 0..x.n {
+                    sums[c][j] += pt[j]
+                }
+                counts[c] += 1
+            }
+
+            let mut new_centroids = centroids.clone();
+            for c in 0..k {
+                // an empty cluster keeps its previous centroid
+                if counts[c] == 0 {
+                    continue;
+                }
+                for j in 0..x.n {
+                    new_centroids[c][j] = sums[c][j] / counts[c] as f64
+                }
+            }
+
+            if new_centroids == centroids {
+                break;
+            }
+            centroids = new_centroids
+        }
+
+        self.centroids = centroids;
+        self.n_iter = n_iter;
+        Ok(())
+    }
+
+    pub fn predict(&self, x: &Matrix<f64>) -> Result<Vec<usize>, CustomErrors> {
+        if self.centroids.is_empty() {
+            return Err(CustomErrors::NotFitted(NotFittedError));
+        }
+
+        let mut labels = vec![];
+        for pt in x.rows() {
+            match nearest_centroid(&pt, &self.centroids) {
+                Ok(c) => labels.push(c),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(labels)
+    }
+}
+
+fn nearest_centroid(pt: &Vec<f64>, centroids: &Vec<Vec<f64>>) -> Result<usize, CustomErrors> {
+    let mut min_dist = f64::MAX;
+    let mut min_idx: usize = 0;
+    for c in 0..centroids.len() {
+        let dist = match cartesian_distance(pt, &centroids[c]) {
+            Ok(dist) => dist,
+            Err(err) => return Err(err),
+        };
+        if dist < min_dist {
+            min_dist = dist;
+            min_idx = c
+        }
+    }
+    Ok(min_idx)
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::error::{CustomErrors, MismatchError};
+    use crate::error::{CustomErrors, InvalidParameterError, MismatchError, NotFittedError};
     use crate::matrix::Matrix;
     use crate::models::cluster::kmeans;
 
-    use super::{cartesian_distance, init_clusters, kpp_init, new_centroids};
+    use super::{cartesian_distance, init_clusters, kpp_init, new_centroids, KMeans};
 
     #[test]
     fn test_kmeans() {
@@ -213,7 +325,10 @@ mod tests {
         match cartesian_distance(&p1, &p2) {
             Ok(_) => 0.0,
             Err(err) => {
-                assert_eq!(err, CustomErrors::Mismatch(MismatchError));
+                assert_eq!(
+                    err,
+                    CustomErrors::Mismatch(MismatchError::new((1, 1), (2, 1)))
+                );
                 0.0
             }
         };
@@ -269,4 +384,55 @@ mod tests {
 
         assert_eq!(target_centroids, centroids)
     }
+
+    #[test]
+    fn test_kmeans_struct() {
+        let rows: Vec<Vec<f64>> = vec![
+            vec![0.0, 0.0],
+            vec![0.2, 0.1],
+            vec![-0.1, 0.2],
+            vec![0.1, -0.3],
+            vec![8.0, 8.0],
+            vec![8.3, 7.9],
+            vec![7.8, 8.2],
+            vec![7.9, 7.9],
+        ];
+        let data = Matrix::new(rows).unwrap();
+
+        let mut model = KMeans::new();
+        match model.predict(&data) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotFitted(NotFittedError)),
+        }
+
+        model.fit(&data, 2, 100, 7).unwrap();
+        assert_eq!(model.centroids.len(), 2);
+
+        let mut found_low = false;
+        let mut found_high = false;
+        for centroid in &model.centroids {
+            if cartesian_distance(centroid, &vec![0.05, 0.0]).unwrap() < 0.5 {
+                found_low = true
+            }
+            if cartesian_distance(centroid, &vec![8.0, 8.0]).unwrap() < 0.5 {
+                found_high = true
+            }
+        }
+        assert!(found_low && found_high);
+
+        let labels = model.predict(&data).unwrap();
+        assert!(labels[..4].iter().all(|&l| l == labels[0]));
+        assert!(labels[4..].iter().all(|&l| l == labels[4]));
+        assert_ne!(labels[0], labels[4]);
+
+        // the same seed gives the same centroids
+        let mut again = KMeans::new();
+        again.fit(&data, 2, 100, 7).unwrap();
+        assert_eq!(again.centroids, model.centroids);
+
+        match model.fit(&data, 9, 100, 7) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::InvalidParameter(InvalidParameterError)),
+        }
+    }
 }
@@ -0,0 +1,306 @@
+use crate::error::{
+    CustomErrors, EmptyVectorError, InvalidParameterError, MismatchError, NotFittedError,
+};
+use crate::matrix::eigen::power_iteration;
+use crate::matrix::operations::{multiply_matrix_vector, outer_product, subtract_matrices};
+use crate::matrix::Matrix;
+use crate::stats::{column_means, covariance_matrix};
+use crate::vector::operations::{dot, gram_schmidt, normalize};
+
+pub struct PCA {
+    pub components: Vec<Vec<f64>>,
+    pub explained_variance: Vec<f64>,
+    pub mean: Vec<f64>,
+}
+
+impl PCA {
+    pub fn new() -> PCA {
+        PCA {
+            components: vec![],
+            explained_variance: vec![],
+            mean: vec![],
+        }
+    }
+
+    pub fn fit(&mut self, x: &Matrix<f64>, n_components: usize) -> Result<(), CustomErrors> {
+        if x.m == 0 || x.n == 0 {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        if n_components == 0 || n_components > x.n {
+            return Err(CustomErrors::InvalidParameter(InvalidParameterError));
+        }
+
+        // covariance_matrix centers the columns itself
+        let covariance = match covariance_matrix(x) {
+            Ok(covariance) => covariance,
+            Err(err) => return Err(err),
+        };
+
+        // variances at or below this count as zero, relative to the largest
+        // covariance entry so the cutoff follows the scale of the data
+        let scale = covariance
+            .data
+            .iter()
+            .fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        let tolerance = 1e-12 * scale;
+
+        let mut deflated = covariance.clone();
+        let mut components: Vec<Vec<f64>> = vec![];
+        let mut explained_variance = vec![];
+        while components.len() < n_components {
+            // nothing but rounding noise is left once the data's rank is used up
+            let remainder = deflated
+                .data
+                .iter()
+                .fold(0.0_f64, |acc, v| acc.max(v.abs()));
+            if remainder <= tolerance {
+                break;
+            }
+
+            let mut component = match power_iteration(&deflated, 10000, 1e-12) {
+                Ok((_, eigenvector)) => eigenvector,
+                Err(err) => return Err(err),
+            };
+
+            // deflation is not exact, remove the components already found so
+            // the result stays orthogonal
+            for found in &components {
+                let projection = match dot(found, &component) {
+                    Ok(projection) => projection,
+                    Err(err) => return Err(err),
+                };
+                for j in 0..x.n {
+                    component[j] -= projection * found[j]
+                }
+            }
+            component = match normalize(&component) {
+                Ok(component) => component,
+                Err(err) => return Err(err),
+            };
+
+            // the variance along the component, measured on the original covariance
+            let product = match multiply_matrix_vector(&covariance, &component) {
+                Ok(product) => product,
+                Err(err) => return Err(err),
+            };
+            let eigenvalue = match dot(&component, &product) {
+                Ok(eigenvalue) => eigenvalue,
+                Err(err) => return Err(err),
+            };
+            if eigenvalue <= tolerance {
+                break;
+            }
+
+            // deflate, C - λvvᵀ, so the next iteration finds the next largest pair
+            let projection = match outer_product(&component, &component) {
+                Ok(projection) => projection.map(|v| v * eigenvalue),
+                Err(err) => return Err(err),
+            };
+            deflated = match subtract_matrices(&deflated, &projection) {
+                Ok(deflated) => deflated,
+                Err(err) => return Err(err),
+            };
+
+            components.push(component);
+            explained_variance.push(eigenvalue)
+        }
+
+        // the data varies in fewer directions than were asked for, fill the
+        // rest with zero variance directions orthogonal to the ones found
+        if components.len() < n_components {
+            let mut candidates = components.clone();
+            for j in 0..x.n {
+                let mut axis = vec![0.0; x.n];
+                axis[j] = 1.0;
+                candidates.push(axis)
+            }
+            let basis = match gram_schmidt(&candidates) {
+                Ok(basis) => basis,
+                Err(err) => return Err(err),
+            };
+
+            let found = components.len();
+            for component in basis.into_iter().skip(found).take(n_components - found) {
+                components.push(component);
+                explained_variance.push(0.0)
+            }
+        }
+
+        self.components = components;
+        self.explained_variance = explained_variance;
+        self.mean = column_means(x);
+        Ok(())
+    }
+
+    pub fn transform(&self, x: &Matrix<f64>) -> Result<Matrix<f64>, CustomErrors> {
+        if self.components.is_empty() {
+            return Err(CustomErrors::NotFitted(NotFittedError));
+        }
+
+        if x.n != self.mean.len() {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (x.m, self.mean.len()),
+                (x.m, x.n),
+            )));
+        }
+
+        let k = self.components.len();
+        let mut projected = Matrix {
+            data: vec![0.0; x.m * k],
+            m: x.m,
+            n: k,
+        };
+        for i in 0..x.m {
+            for c in 0..k {
+                let mut value = 0.0;
+                for j in 0..x.n {
+                    value += (x[(i, j)] - self.mean[j]) * self.components[c][j]
+                }
+                projected[(i, c)] = value
+            }
+        }
+
+        Ok(projected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, InvalidParameterError, MismatchError, NotFittedError};
+    use crate::matrix::operations::multiply_matrix_vector;
+    use crate::matrix::Matrix;
+    use crate::stats::{column_variances, covariance_matrix};
+    use crate::vector::operations::dot;
+
+    use super::PCA;
+
+    #[test]
+    fn test_pca() {
+        // spread along x, barely any along y
+        let x = Matrix::new(vec![
+            vec![-4.0, 0.1],
+            vec![-2.0, -0.1],
+            vec![0.0, 0.05],
+            vec![2.0, -0.05],
+            vec![4.0, 0.0],
+        ])
+        .unwrap();
+
+        let mut pca = PCA::new();
+        match pca.transform(&x) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotFitted(NotFittedError)),
+        }
+
+        pca.fit(&x, 2).unwrap();
+        assert_eq!(pca.components.len(), 2);
+        assert!(pca.components[0][0].abs() > 0.999);
+        assert!(pca.components[1][1].abs() > 0.999);
+        assert!(pca.explained_variance[0] > pca.explained_variance[1]);
+
+        let projected = pca.transform(&x).unwrap();
+        assert_eq!((projected.m, projected.n), (5, 2));
+
+        // the variance along each component is its eigenvalue
+        let variances = column_variances(&projected);
+        for c in 0..2 {
+            assert!((variances[c] - pca.explained_variance[c]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_pca_axis_orthogonal_to_ones() {
+        // spread along x = -y, the major axis [1, -1] is orthogonal to [1, 1]
+        let x = Matrix::new(vec![
+            vec![-3.0, 3.0],
+            vec![-1.0, 1.0],
+            vec![1.0, -1.0],
+            vec![3.0, -3.0],
+            vec![0.1, 0.1],
+            vec![-0.1, -0.1],
+        ])
+        .unwrap();
+
+        let mut pca = PCA::new();
+        pca.fit(&x, 2).unwrap();
+
+        let major = &pca.components[0];
+        assert!((major[0] + major[1]).abs() < 1e-9);
+        assert!((major[0].abs() - 0.5_f64.sqrt()).abs() < 1e-9);
+        assert!(pca.explained_variance[0] > 7.9);
+
+        // each component is an eigenpair of the original, undeflated covariance
+        let covariance = covariance_matrix(&x).unwrap();
+        for c in 0..2 {
+            let component = &pca.components[c];
+            let product = multiply_matrix_vector(&covariance, component).unwrap();
+            for j in 0..2 {
+                let expected = pca.explained_variance[c] * component[j];
+                assert!((product[j] - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pca_constant_column() {
+        // the second feature never varies, the covariance has rank one
+        let x = Matrix::new(vec![vec![1.0, 5.0], vec![2.0, 5.0], vec![3.0, 5.0]]).unwrap();
+
+        let mut pca = PCA::new();
+        pca.fit(&x, 2).unwrap();
+        assert_eq!(pca.components.len(), 2);
+
+        assert!((pca.components[0][0].abs() - 1.0).abs() < 1e-9);
+        assert!((pca.explained_variance[0] - 1.0).abs() < 1e-9);
+
+        // the remaining direction is orthogonal and carries no variance
+        assert!(dot(&pca.components[0], &pca.components[1]).unwrap().abs() < 1e-9);
+        assert!((pca.components[1][1].abs() - 1.0).abs() < 1e-9);
+        assert_eq!(pca.explained_variance[1], 0.0);
+    }
+
+    #[test]
+    fn test_pca_small_scale() {
+        // the x = -y data above scaled down to entries around 1e-7
+        let rows = vec![
+            vec![-3.0, 3.0],
+            vec![-1.0, 1.0],
+            vec![1.0, -1.0],
+            vec![3.0, -3.0],
+            vec![0.1, 0.1],
+            vec![-0.1, -0.1],
+        ];
+        let x = Matrix::new(rows).unwrap().map(|v| v * 1e-7);
+
+        let mut pca = PCA::new();
+        pca.fit(&x, 2).unwrap();
+
+        let major = &pca.components[0];
+        assert!((major[0] + major[1]).abs() < 1e-9);
+        assert!(pca.explained_variance[0] > pca.explained_variance[1]);
+        assert!(pca.explained_variance[1] > 0.0);
+        assert!(dot(&pca.components[0], &pca.components[1]).unwrap().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pca_errors() {
+        let x = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 5.0], vec![4.0, 4.0]]).unwrap();
+        let mut pca = PCA::new();
+
+        match pca.fit(&x, 3) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::InvalidParameter(InvalidParameterError)),
+        }
+
+        pca.fit(&x, 1).unwrap();
+        let wrong = Matrix::new(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+        match pca.transform(&wrong) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((1, 2), (1, 3)))
+            ),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::error::{CustomErrors, EmptyVectorError, IndexOutOfBoundsError};
+use crate::matrix::Matrix;
+
+pub fn one_hot(labels: &[u32], num_classes: usize) -> Result<Matrix<f64>, CustomErrors> {
+    if labels.is_empty() || num_classes == 0 {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    let mut encoded = Matrix {
+        data: vec![0.0; labels.len() * num_classes],
+        m: labels.len(),
+        n: num_classes,
+    };
+    for i in 0..labels.len() {
+        let class = labels[i] as usize;
+        if class >= num_classes {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+        encoded[(i, class)] = 1.0
+    }
+
+    Ok(encoded)
+}
+
+pub struct LabelEncoder {
+    pub classes: Vec<String>,
+}
+
+impl LabelEncoder {
+    pub fn new() -> LabelEncoder {
+        LabelEncoder { classes: vec![] }
+    }
+
+    // ids follow the sorted order of the distinct labels
+    pub fn fit_transform(&mut self, labels: &[String]) -> Vec<u32> {
+        let mut classes = labels.to_vec();
+        classes.sort();
+        classes.dedup();
+        self.classes = classes;
+
+        let mut ids: Vec<u32> = vec![];
+        for label in labels {
+            match self.classes.binary_search(label) {
+                Ok(idx) => ids.push(idx as u32),
+                Err(_) => unreachable!("every label was added to the classes"),
+            }
+        }
+        ids
+    }
+
+    pub fn inverse_transform(&self, ids: &[u32]) -> Result<Vec<String>, CustomErrors> {
+        let mut labels: Vec<String> = vec![];
+        for i in 0..ids.len() {
+            let idx = ids[i] as usize;
+            if idx >= self.classes.len() {
+                return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+            }
+            labels.push(self.classes[idx].clone())
+        }
+        Ok(labels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, EmptyVectorError, IndexOutOfBoundsError};
+    use crate::matrix::Matrix;
+
+    use super::{one_hot, LabelEncoder};
+
+    #[test]
+    fn test_one_hot() {
+        let encoded = one_hot(&[0, 2, 1, 2], 3).unwrap();
+        let target = Matrix::new(vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+        assert_eq!(encoded, target);
+
+        match one_hot(&[0, 3], 3) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+
+        match one_hot(&[], 3) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_label_encoder() {
+        let labels: Vec<String> = vec!["dog", "cat", "bird", "cat", "dog"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let mut encoder = LabelEncoder::new();
+        let ids = encoder.fit_transform(&labels);
+        assert_eq!(ids, vec![2, 1, 0, 1, 2]);
+        assert_eq!(encoder.classes, vec!["bird", "cat", "dog"]);
+
+        assert_eq!(encoder.inverse_transform(&ids).unwrap(), labels);
+
+        match encoder.inverse_transform(&[0, 3]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+    }
+}
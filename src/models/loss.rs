@@ -0,0 +1,95 @@
+use crate::error::{CustomErrors, EmptyVectorError, MismatchError};
+
+fn check_lengths(y_true: &[f64], y_pred: &[f64]) -> Result<(), CustomErrors> {
+    if y_true.len() != y_pred.len() {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (y_true.len(), 1),
+            (y_pred.len(), 1),
+        )));
+    }
+
+    if y_true.is_empty() {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    Ok(())
+}
+
+pub fn mse(y_true: &[f64], y_pred: &[f64]) -> Result<f64, CustomErrors> {
+    match check_lengths(y_true, y_pred) {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    };
+
+    let mut sum = 0.0;
+    for i in 0..y_true.len() {
+        sum += (y_true[i] - y_pred[i]).powi(2)
+    }
+
+    Ok(sum / y_true.len() as f64)
+}
+
+// mean binary cross-entropy, y_true holds 0/1 targets and y_pred probabilities
+pub fn cross_entropy(y_true: &[f64], y_pred: &[f64]) -> Result<f64, CustomErrors> {
+    match check_lengths(y_true, y_pred) {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    };
+
+    // keep predictions off 0 and 1 so the logs stay finite
+    let eps = 1e-15;
+    let mut sum = 0.0;
+    for i in 0..y_true.len() {
+        let p = y_pred[i].clamp(eps, 1.0 - eps);
+        sum -= y_true[i] * p.ln() + (1.0 - y_true[i]) * (1.0 - p).ln()
+    }
+
+    Ok(sum / y_true.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, EmptyVectorError, MismatchError};
+
+    use super::{cross_entropy, mse};
+
+    #[test]
+    fn test_mse() {
+        assert_eq!(mse(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]).unwrap(), 0.0);
+        assert_eq!(mse(&[1.0, 2.0, 3.0], &[2.0, 2.0, 1.0]).unwrap(), 5.0 / 3.0);
+
+        match mse(&[1.0, 2.0], &[1.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (1, 1)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_cross_entropy() {
+        let loss = cross_entropy(&[1.0, 0.0], &[0.8, 0.2]).unwrap();
+        assert!((loss - 0.2231435513142097).abs() < 1e-12);
+
+        // confident wrong predictions are clamped rather than infinite
+        let loss = cross_entropy(&[1.0, 0.0], &[0.0, 1.0]).unwrap();
+        assert!(loss.is_finite());
+        assert!(loss > 30.0);
+
+        assert!(cross_entropy(&[1.0, 0.0], &[1.0, 0.0]).unwrap() < 1e-12);
+
+        match cross_entropy(&[], &[]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+
+        match cross_entropy(&[1.0], &[0.5, 0.5]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((1, 1), (2, 1)))
+            ),
+        }
+    }
+}
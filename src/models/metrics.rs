@@ -0,0 +1,213 @@
+use crate::error::{CustomErrors, EmptyVectorError, IndexOutOfBoundsError, MismatchError};
+use crate::matrix::Matrix;
+
+fn check_lengths(y_true: &[u32], y_pred: &[u32]) -> Result<(), CustomErrors> {
+    if y_true.len() != y_pred.len() {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (y_true.len(), 1),
+            (y_pred.len(), 1),
+        )));
+    }
+
+    if y_true.is_empty() {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    Ok(())
+}
+
+// true positive, false positive and false negative counts for one class
+fn positive_counts(y_true: &[u32], y_pred: &[u32], positive: u32) -> (f64, f64, f64) {
+    let mut tp = 0.0;
+    let mut fp = 0.0;
+    let mut fn_ = 0.0;
+    for i in 0..y_true.len() {
+        if y_pred[i] == positive && y_true[i] == positive {
+            tp += 1.0
+        } else if y_pred[i] == positive {
+            fp += 1.0
+        } else if y_true[i] == positive {
+            fn_ += 1.0
+        }
+    }
+    (tp, fp, fn_)
+}
+
+pub fn accuracy(y_true: &[u32], y_pred: &[u32]) -> Result<f64, CustomErrors> {
+    match check_lengths(y_true, y_pred) {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    };
+
+    let mut correct = 0.0;
+    for i in 0..y_true.len() {
+        if y_true[i] == y_pred[i] {
+            correct += 1.0
+        }
+    }
+
+    Ok(correct / y_true.len() as f64)
+}
+
+pub fn precision(y_true: &[u32], y_pred: &[u32], positive: u32) -> Result<f64, CustomErrors> {
+    match check_lengths(y_true, y_pred) {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    };
+
+    let (tp, fp, _) = positive_counts(y_true, y_pred, positive);
+    // the class was never predicted
+    if tp + fp == 0.0 {
+        return Ok(0.0);
+    }
+
+    Ok(tp / (tp + fp))
+}
+
+pub fn recall(y_true: &[u32], y_pred: &[u32], positive: u32) -> Result<f64, CustomErrors> {
+    match check_lengths(y_true, y_pred) {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    };
+
+    let (tp, _, fn_) = positive_counts(y_true, y_pred, positive);
+    // the class never appears in y_true
+    if tp + fn_ == 0.0 {
+        return Ok(0.0);
+    }
+
+    Ok(tp / (tp + fn_))
+}
+
+pub fn f1_score(y_true: &[u32], y_pred: &[u32], positive: u32) -> Result<f64, CustomErrors> {
+    let p = match precision(y_true, y_pred, positive) {
+        Ok(p) => p,
+        Err(err) => return Err(err),
+    };
+    let r = match recall(y_true, y_pred, positive) {
+        Ok(r) => r,
+        Err(err) => return Err(err),
+    };
+
+    if p + r == 0.0 {
+        return Ok(0.0);
+    }
+
+    Ok(2.0 * p * r / (p + r))
+}
+
+pub fn confusion_matrix(
+    y_true: &[u32],
+    y_pred: &[u32],
+    num_classes: usize,
+) -> Result<Matrix<u32>, CustomErrors> {
+    match check_lengths(y_true, y_pred) {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    };
+
+    if num_classes == 0 {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    // rows are the true class, columns the predicted one
+    let mut counts = Matrix {
+        data: vec![0; num_classes * num_classes],
+        m: num_classes,
+        n: num_classes,
+    };
+    for k in 0..y_true.len() {
+        let i = y_true[k] as usize;
+        let j = y_pred[k] as usize;
+        if i >= num_classes || j >= num_classes {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+        counts[(i, j)] += 1
+    }
+
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, EmptyVectorError, IndexOutOfBoundsError, MismatchError};
+    use crate::matrix::Matrix;
+
+    use super::{accuracy, confusion_matrix, f1_score, precision, recall};
+
+    #[test]
+    fn test_classification_metrics() {
+        // for class 1: tp = 3, fp = 1, fn = 2, tn = 2
+        let y_true = [1, 1, 1, 1, 1, 0, 0, 0];
+        let y_pred = [1, 1, 1, 0, 0, 1, 0, 0];
+
+        assert_eq!(accuracy(&y_true, &y_pred).unwrap(), 5.0 / 8.0);
+        assert_eq!(precision(&y_true, &y_pred, 1).unwrap(), 3.0 / 4.0);
+        assert_eq!(recall(&y_true, &y_pred, 1).unwrap(), 3.0 / 5.0);
+        let f1 = f1_score(&y_true, &y_pred, 1).unwrap();
+        assert!((f1 - 2.0 / 3.0).abs() < 1e-12);
+
+        // for class 0: tp = 2, fp = 2, fn = 1
+        assert_eq!(precision(&y_true, &y_pred, 0).unwrap(), 0.5);
+        assert_eq!(recall(&y_true, &y_pred, 0).unwrap(), 2.0 / 3.0);
+
+        // a class that is never predicted nor present
+        assert_eq!(precision(&y_true, &y_pred, 2).unwrap(), 0.0);
+        assert_eq!(recall(&y_true, &y_pred, 2).unwrap(), 0.0);
+        assert_eq!(f1_score(&y_true, &y_pred, 2).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_metrics_errors() {
+        match accuracy(&[1, 0, 1], &[1, 0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 1), (2, 1)))
+            ),
+        }
+
+        match precision(&[1], &[1, 0], 1) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((1, 1), (2, 1)))
+            ),
+        }
+
+        match recall(&[], &[], 1) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+
+        match f1_score(&[0, 1], &[1], 1) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (1, 1)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_confusion_matrix() {
+        let y_true = [0, 0, 1, 1, 1, 2, 2, 2];
+        let y_pred = [0, 1, 1, 1, 2, 2, 2, 0];
+
+        let target = Matrix::new(vec![vec![1, 1, 0], vec![0, 2, 1], vec![1, 0, 2]]).unwrap();
+        assert_eq!(confusion_matrix(&y_true, &y_pred, 3).unwrap(), target);
+
+        match confusion_matrix(&y_true, &y_pred[..7], 3) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((8, 1), (7, 1)))
+            ),
+        }
+
+        match confusion_matrix(&y_true, &y_pred, 2) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+    }
+}
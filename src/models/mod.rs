@@ -1,5 +1,13 @@
+pub mod activations;
 pub mod classifier;
 pub mod cluster;
+pub mod decomposition;
+pub mod encoding;
+pub mod loss;
+pub mod metrics;
+pub mod preprocessing;
+pub mod regression;
+pub mod selection;
 use crate::matrix::{inverse::cramer_inverse, operations::multiply_matrices, Matrix};
 
 pub fn linear_regression(x: &Matrix<f64>, y: &Matrix<f64>) -> Matrix<f64> {
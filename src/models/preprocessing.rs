@@ -0,0 +1,125 @@
+use crate::error::{CustomErrors, EmptyVectorError, MismatchError, NotFittedError};
+use crate::matrix::Matrix;
+use crate::vector::operations::{mean, stddev};
+
+pub struct StandardScaler {
+    pub means: Vec<f64>,
+    pub stds: Vec<f64>,
+}
+
+impl StandardScaler {
+    pub fn new() -> StandardScaler {
+        StandardScaler {
+            means: vec![],
+            stds: vec![],
+        }
+    }
+
+    pub fn fit(&mut self, x: &Matrix<f64>) -> Result<(), CustomErrors> {
+        if x.m == 0 || x.n == 0 {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        let mut means = vec![];
+        let mut stds = vec![];
+        for j in 0..x.n {
+            let column = match x.col(j) {
+                Ok(column) => column,
+                Err(err) => return Err(err),
+            };
+            match mean(&column) {
+                Ok(mn) => means.push(mn),
+                Err(err) => return Err(err),
+            };
+            match stddev(&column) {
+                Ok(stdd) => stds.push(stdd),
+                Err(err) => return Err(err),
+            };
+        }
+
+        self.means = means;
+        self.stds = stds;
+        Ok(())
+    }
+
+    pub fn transform(&self, x: &Matrix<f64>) -> Result<Matrix<f64>, CustomErrors> {
+        if self.means.is_empty() {
+            return Err(CustomErrors::NotFitted(NotFittedError));
+        }
+
+        if x.n != self.means.len() {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (x.m, self.means.len()),
+                (x.m, x.n),
+            )));
+        }
+
+        let mut scaled = x.clone();
+        for j in 0..x.n {
+            // constant columns have nothing to scale by, leave them as they are.
+            // a float constant like 0.1 rarely gives a std of exactly zero, so
+            // compare against the size of the mean instead
+            if self.stds[j] <= 1e-12 * self.means[j].abs().max(1.0) {
+                continue;
+            }
+            for i in 0..x.m {
+                scaled[(i, j)] = (x[(i, j)] - self.means[j]) / self.stds[j]
+            }
+        }
+
+        Ok(scaled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, MismatchError, NotFittedError};
+    use crate::matrix::Matrix;
+    use crate::vector::operations::{mean, stddev};
+
+    use super::StandardScaler;
+
+    #[test]
+    fn test_standard_scaler() {
+        let x = Matrix::new(vec![
+            vec![1.0, 10.0, 7.0],
+            vec![2.0, 30.0, 7.0],
+            vec![6.0, 20.0, 7.0],
+            vec![3.0, 60.0, 7.0],
+        ])
+        .unwrap();
+
+        let mut scaler = StandardScaler::new();
+        match scaler.transform(&x) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotFitted(NotFittedError)),
+        }
+
+        scaler.fit(&x).unwrap();
+        let scaled = scaler.transform(&x).unwrap();
+        for j in 0..2 {
+            let column = scaled.col(j).unwrap();
+            assert!(mean(&column).unwrap().abs() < 1e-12);
+            assert!((stddev(&column).unwrap() - 1.0).abs() < 1e-12);
+        }
+
+        // zero variance column is left unchanged
+        assert_eq!(scaled.col(2).unwrap(), vec![7.0, 7.0, 7.0, 7.0]);
+
+        // 0.1 is not exactly representable, its std comes out as roughly 1e-17
+        // rather than zero but the column is still constant
+        let tenths = Matrix::new(vec![vec![0.1], vec![0.1], vec![0.1]]).unwrap();
+        let mut tenths_scaler = StandardScaler::new();
+        tenths_scaler.fit(&tenths).unwrap();
+        assert_eq!(tenths_scaler.transform(&tenths).unwrap(), tenths);
+
+        let wrong = Matrix::new(vec![vec![1.0, 2.0]]).unwrap();
+        match scaler.transform(&wrong) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((1, 3), (1, 2)))
+            ),
+        }
+    }
+}
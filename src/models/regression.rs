@@ -0,0 +1,379 @@
+use crate::algebra::solve;
+use crate::error::{
+    CustomErrors, EmptyVectorError, InvalidParameterError, MismatchError, NotFittedError,
+};
+use crate::matrix::operations::{hstack, multiply_matrices, multiply_matrix_vector};
+use crate::matrix::Matrix;
+use crate::models::{classifier::logreg::vector_logistic, loss::cross_entropy};
+use crate::vector::operations::{scalar_multiply, sub_vec};
+use crate::vector::util::{ones, zeroes};
+
+pub struct LinearRegression {
+    pub fit_intercept: bool,
+    pub coefficients: Vec<f64>,
+    pub intercept: f64,
+}
+
+impl LinearRegression {
+    pub fn new(fit_intercept: bool) -> LinearRegression {
+        LinearRegression {
+            fit_intercept,
+            coefficients: vec![],
+            intercept: 0.0,
+        }
+    }
+
+    pub fn fit(&mut self, x: &Matrix<f64>, y: &Vec<f64>) -> Result<(), CustomErrors> {
+        if x.m == 0 || x.n == 0 {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        if y.len() != x.m {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (x.m, 1),
+                (y.len(), 1),
+            )));
+        }
+
+        // the intercept is fit as the coefficient of a leading column of ones
+        let design = if self.fit_intercept {
+            let intercept_column = match ones(x.m, 1) {
+                Ok(column) => column,
+                Err(err) => return Err(err),
+            };
+            match hstack(&intercept_column, x) {
+                Ok(design) => design,
+                Err(err) => return Err(err),
+            }
+        } else {
+            x.clone()
+        };
+
+        // normal equations, (XᵀX)β = Xᵀy
+        let design_t = design.transpose();
+        let gram = match multiply_matrices(&design_t, &design) {
+            Ok(gram) => gram,
+            Err(err) => return Err(err),
+        };
+        let moment = match multiply_matrix_vector(&design_t, y) {
+            Ok(moment) => moment,
+            Err(err) => return Err(err),
+        };
+        let beta = match solve(&gram, &moment) {
+            Ok(beta) => beta,
+            Err(err) => return Err(err),
+        };
+
+        if self.fit_intercept {
+            self.intercept = beta[0];
+            self.coefficients = beta[1..].to_vec();
+        } else {
+            self.intercept = 0.0;
+            self.coefficients = beta;
+        }
+
+        Ok(())
+    }
+
+    pub fn predict(&self, x: &Matrix<f64>) -> Result<Vec<f64>, CustomErrors> {
+        if self.coefficients.is_empty() {
+            return Err(CustomErrors::NotFitted(NotFittedError));
+        }
+
+        let predictions = match multiply_matrix_vector(x, &self.coefficients) {
+            Ok(predictions) => predictions,
+            Err(_) => {
+                return Err(CustomErrors::Mismatch(MismatchError::new(
+                    (x.m, self.coefficients.len()),
+                    (x.m, x.n),
+                )))
+            }
+        };
+
+        let mut shifted: Vec<f64> = vec![];
+        for i in 0..predictions.len() {
+            shifted.push(predictions[i] + self.intercept)
+        }
+        Ok(shifted)
+    }
+}
+
+pub struct LogisticRegression {
+    pub coefficients: Vec<f64>,
+    pub losses: Vec<f64>,
+}
+
+impl LogisticRegression {
+    pub fn new() -> LogisticRegression {
+        LogisticRegression {
+            coefficients: vec![],
+            losses: vec![],
+        }
+    }
+
+    pub fn fit(
+        &mut self,
+        x: &Matrix<f64>,
+        y: &Vec<u32>,
+        lr: f64,
+        epochs: usize,
+    ) -> Result<(), CustomErrors> {
+        if x.m == 0 || x.n == 0 {
+            return Err(CustomErrors::EmptyVector(EmptyVectorError));
+        }
+
+        if y.len() != x.m {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (x.m, 1),
+                (y.len(), 1),
+            )));
+        }
+
+        let mut targets: Vec<f64> = vec![];
+        for i in 0..y.len() {
+            if y[i] > 1 {
+                return Err(CustomErrors::InvalidParameter(InvalidParameterError));
+            }
+            targets.push(y[i] as f64)
+        }
+
+        // the bias is the coefficient of a leading column of ones
+        let design = match design_matrix(x) {
+            Ok(design) => design,
+            Err(err) => return Err(err),
+        };
+        let design_t = design.transpose();
+        let n_samples: f64 = x.m as f64;
+
+        self.coefficients = zeroes(design.n);
+        self.losses = vec![];
+        for _ in 0..epochs {
+            let z = match multiply_matrix_vector(&design, &self.coefficients) {
+                Ok(z) => z,
+                Err(err) => return Err(err),
+            };
+            let out = vector_logistic(&z);
+
+            let loss = match cross_entropy(&targets, &out) {
+                Ok(loss) => loss,
+                Err(err) => return Err(err),
+            };
+            self.losses.push(loss);
+
+            // gradient of the mean cross-entropy, Xᵀ(σ(Xw) - y) / m
+            let dz = match sub_vec(&out, &targets) {
+                Ok(dz) => dz,
+                Err(err) => return Err(err),
+            };
+            let gradient = match multiply_matrix_vector(&design_t, &dz) {
+                Ok(gradient) => gradient,
+                Err(err) => return Err(err),
+            };
+            let increment = match scalar_multiply(&gradient, lr / n_samples) {
+                Ok(increment) => increment,
+                Err(err) => return Err(err),
+            };
+            self.coefficients = match sub_vec(&self.coefficients, &increment) {
+                Ok(coefficients) => coefficients,
+                Err(err) => return Err(err),
+            };
+        }
+
+        Ok(())
+    }
+
+    pub fn predict_proba(&self, x: &Matrix<f64>) -> Result<Vec<f64>, CustomErrors> {
+        if self.coefficients.is_empty() {
+            return Err(CustomErrors::NotFitted(NotFittedError));
+        }
+
+        let n_features = self.coefficients.len() - 1;
+        if x.n != n_features {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (x.m, n_features),
+                (x.m, x.n),
+            )));
+        }
+
+        let design = match design_matrix(x) {
+            Ok(design) => design,
+            Err(err) => return Err(err),
+        };
+        let z = match multiply_matrix_vector(&design, &self.coefficients) {
+            Ok(z) => z,
+            Err(err) => return Err(err),
+        };
+
+        Ok(vector_logistic(&z))
+    }
+
+    pub fn predict(&self, x: &Matrix<f64>) -> Result<Vec<u32>, CustomErrors> {
+        let probabilities = match self.predict_proba(x) {
+            Ok(probabilities) => probabilities,
+            Err(err) => return Err(err),
+        };
+
+        let mut classes: Vec<u32> = vec![];
+        for i in 0..probabilities.len() {
+            if probabilities[i] > 0.5 {
+                classes.push(1)
+            } else {
+                classes.push(0)
+            }
+        }
+        Ok(classes)
+    }
+}
+
+fn design_matrix(x: &Matrix<f64>) -> Result<Matrix<f64>, CustomErrors> {
+    let bias_column = match ones(x.m, 1) {
+        Ok(column) => column,
+        Err(err) => return Err(err),
+    };
+    hstack(&bias_column, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, InvalidParameterError, MismatchError, NotFittedError};
+    use crate::matrix::Matrix;
+
+    use super::{LinearRegression, LogisticRegression};
+
+    #[test]
+    fn test_fit_with_intercept() {
+        // y = 2x + 1
+        let x = Matrix::new(vec![vec![0.0], vec![1.0], vec![2.0], vec![3.0], vec![4.0]]).unwrap();
+        let y = vec![1.0, 3.0, 5.0, 7.0, 9.0];
+
+        let mut model = LinearRegression::new(true);
+        model.fit(&x, &y).unwrap();
+        assert!((model.intercept - 1.0).abs() < 1e-9);
+        assert_eq!(model.coefficients.len(), 1);
+        assert!((model.coefficients[0] - 2.0).abs() < 1e-9);
+
+        let held_out = Matrix::new(vec![vec![10.0], vec![-1.0]]).unwrap();
+        let predictions = model.predict(&held_out).unwrap();
+        assert!((predictions[0] - 21.0).abs() < 1e-9);
+        assert!((predictions[1] + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fit_without_intercept() {
+        // y = 3a - b, no constant term
+        let x = Matrix::new(vec![
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+            vec![1.0, 1.0],
+            vec![2.0, 1.0],
+        ])
+        .unwrap();
+        let y = vec![3.0, -1.0, 2.0, 5.0];
+
+        let mut model = LinearRegression::new(false);
+        model.fit(&x, &y).unwrap();
+        assert_eq!(model.intercept, 0.0);
+        assert!((model.coefficients[0] - 3.0).abs() < 1e-9);
+        assert!((model.coefficients[1] + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_errors() {
+        let x = Matrix::new(vec![vec![1.0], vec![2.0]]).unwrap();
+        let mut model = LinearRegression::new(true);
+
+        match model.predict(&x) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotFitted(NotFittedError)),
+        }
+
+        match model.fit(&x, &vec![1.0, 2.0, 3.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (3, 1)))
+            ),
+        }
+
+        model.fit(&x, &vec![1.0, 2.0]).unwrap();
+        let wide = Matrix::new(vec![vec![1.0, 2.0]]).unwrap();
+        match model.predict(&wide) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((1, 1), (1, 2)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_logistic_regression() {
+        let x = Matrix::new(vec![
+            vec![-3.0],
+            vec![-2.0],
+            vec![-1.5],
+            vec![-1.0],
+            vec![-0.5],
+            vec![0.5],
+            vec![1.0],
+            vec![1.5],
+            vec![2.0],
+            vec![3.0],
+        ])
+        .unwrap();
+        let y: Vec<u32> = vec![0, 0, 0, 0, 0, 1, 1, 1, 1, 1];
+
+        let mut model = LogisticRegression::new();
+        match model.predict(&x) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::NotFitted(NotFittedError)),
+        }
+
+        model.fit(&x, &y, 0.5, 500).unwrap();
+        assert_eq!(model.coefficients.len(), 2);
+        assert_eq!(model.losses.len(), 500);
+        assert!(model.losses[499] < model.losses[0]);
+
+        let predictions = model.predict(&x).unwrap();
+        let mut correct = 0;
+        for i in 0..y.len() {
+            if predictions[i] == y[i] {
+                correct += 1
+            }
+        }
+        assert!(correct as f64 / y.len() as f64 >= 0.9);
+
+        let probs = model.predict_proba(&x).unwrap();
+        assert!(probs[0] < 0.1);
+        assert!(probs[9] > 0.9);
+    }
+
+    #[test]
+    fn test_logistic_regression_errors() {
+        let x = Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap();
+        let mut model = LogisticRegression::new();
+
+        match model.fit(&x, &vec![0], 0.1, 10) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (1, 1)))
+            ),
+        }
+
+        match model.fit(&x, &vec![0, 2], 0.1, 10) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::InvalidParameter(InvalidParameterError)),
+        }
+
+        model.fit(&x, &vec![0, 1], 0.1, 10).unwrap();
+        let wrong = Matrix::new(vec![vec![1.0]]).unwrap();
+        match model.predict_proba(&wrong) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((1, 2), (1, 1)))
+            ),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::{
+    error::{CustomErrors, EmptyVectorError, InvalidParameterError, MismatchError},
+    matrix::Matrix,
+};
+
+pub fn train_test_split(
+    x: &Matrix<f64>,
+    y: &Vec<u32>,
+    test_ratio: f64,
+    seed: u64,
+) -> Result<(Matrix<f64>, Vec<u32>, Matrix<f64>, Vec<u32>), CustomErrors> {
+    if x.m != y.len() {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (x.m, 1),
+            (y.len(), 1),
+        )));
+    }
+
+    if !(test_ratio > 0.0 && test_ratio < 1.0) {
+        return Err(CustomErrors::InvalidParameter(InvalidParameterError));
+    }
+
+    // both splits need at least one row
+    if x.m < 2 {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    let mut indices: Vec<usize> = (0..x.m).collect();
+    let mut rng = StdRng::seed_from_u64(seed);
+    indices.shuffle(&mut rng);
+
+    let n_test = ((x.m as f64 * test_ratio).round() as usize).clamp(1, x.m - 1);
+    let n_train = x.m - n_test;
+
+    let mut train_data: Vec<f64> = Vec::with_capacity(n_train * x.n);
+    let mut train_labels: Vec<u32> = Vec::with_capacity(n_train);
+    for &i in &indices[..n_train] {
+        train_data.extend_from_slice(&x.data[i * x.n..(i + 1) * x.n]);
+        train_labels.push(y[i])
+    }
+
+    let mut test_data: Vec<f64> = Vec::with_capacity(n_test * x.n);
+    let mut test_labels: Vec<u32> = Vec::with_capacity(n_test);
+    for &i in &indices[n_train..] {
+        test_data.extend_from_slice(&x.data[i * x.n..(i + 1) * x.n]);
+        test_labels.push(y[i])
+    }
+
+    let x_train = match Matrix::from_vec(train_data, n_train, x.n) {
+        Ok(matrix) => matrix,
+        Err(err) => return Err(err),
+    };
+    let x_test = match Matrix::from_vec(test_data, n_test, x.n) {
+        Ok(matrix) => matrix,
+        Err(err) => return Err(err),
+    };
+
+    Ok((x_train, train_labels, x_test, test_labels))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, InvalidParameterError, MismatchError};
+    use crate::matrix::Matrix;
+
+    use super::train_test_split;
+
+    #[test]
+    fn test_train_test_split() {
+        // every row holds its own index so rows can be traced through the split
+        let mut data = vec![];
+        for i in 0..10 {
+            data.push(i as f64);
+            data.push(i as f64 * 10.0);
+        }
+        let x = Matrix::from_vec(data, 10, 2).unwrap();
+        let y: Vec<u32> = (0..10).collect();
+
+        let (x_train, y_train, x_test, y_test) = train_test_split(&x, &y, 0.3, 42).unwrap();
+        assert_eq!(x_train.m, 7);
+        assert_eq!(x_test.m, 3);
+        assert_eq!(x_train.m + x_test.m, x.m);
+        assert_eq!(y_train.len(), x_train.m);
+        assert_eq!(y_test.len(), x_test.m);
+
+        // labels stay attached to their rows and nothing appears in both splits
+        for i in 0..x_train.m {
+            assert_eq!(x_train[(i, 0)], y_train[i] as f64);
+        }
+        for i in 0..x_test.m {
+            assert_eq!(x_test[(i, 0)], y_test[i] as f64);
+        }
+        let mut seen = y_train.clone();
+        seen.extend(y_test.iter());
+        seen.sort();
+        assert_eq!(seen, y);
+
+        let again = train_test_split(&x, &y, 0.3, 42).unwrap();
+        assert_eq!(again.0, x_train);
+        assert_eq!(again.1, y_train);
+        assert_eq!(again.2, x_test);
+        assert_eq!(again.3, y_test);
+    }
+
+    #[test]
+    fn test_train_test_split_errors() {
+        let x = Matrix::new(vec![vec![1.0], vec![2.0], vec![3.0]]).unwrap();
+
+        match train_test_split(&x, &vec![0, 1], 0.5, 0) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 1), (2, 1)))
+            ),
+        }
+
+        for ratio in [0.0, 1.0, -0.5, 1.5] {
+            match train_test_split(&x, &vec![0, 1, 0], ratio, 0) {
+                Ok(_) => assert!(false),
+                Err(err) => assert_eq!(err, CustomErrors::InvalidParameter(InvalidParameterError)),
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::error::{CustomErrors, IndexOutOfBoundsError, MismatchError};
+use crate::matrix::Matrix;
+
+// compressed sparse row storage. the non-zero entries of row i live in
+// values[row_ptr[i]..row_ptr[i + 1]], with their columns in col_indices
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix<T: Copy> {
+    pub values: Vec<T>,
+    pub col_indices: Vec<usize>,
+    pub row_ptr: Vec<usize>,
+    pub m: usize,
+    pub n: usize,
+}
+
+impl<T: Copy + From<u8> + PartialEq> SparseMatrix<T> {
+    pub fn from_dense(matrix: &Matrix<T>) -> SparseMatrix<T> {
+        let zero_cast: T = 0.into();
+        let mut values: Vec<T> = vec![];
+        let mut col_indices: Vec<usize> = vec![];
+        let mut row_ptr: Vec<usize> = vec![0];
+        for row in matrix.iter_rows() {
+            for j in 0..matrix.n {
+                if row[j] != zero_cast {
+                    values.push(row[j]);
+                    col_indices.push(j)
+                }
+            }
+            row_ptr.push(values.len())
+        }
+
+        SparseMatrix {
+            values,
+            col_indices,
+            row_ptr,
+            m: matrix.m,
+            n: matrix.n,
+        }
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let zero_cast: T = 0.into();
+        let mut data: Vec<T> = vec![zero_cast; self.m * self.n];
+        for i in 0..self.m {
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                data[i * self.n + self.col_indices[k]] = self.values[k]
+            }
+        }
+
+        Matrix {
+            data,
+            m: self.m,
+            n: self.n,
+        }
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Result<T, CustomErrors> {
+        if i >= self.m || j >= self.n {
+            return Err(CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError));
+        }
+
+        // columns within a row are stored in increasing order
+        let start = self.row_ptr[i];
+        let end = self.row_ptr[i + 1];
+        match self.col_indices[start..end].binary_search(&j) {
+            Ok(k) => Ok(self.values[start + k]),
+            Err(_) => Ok(0.into()),
+        }
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<T: Copy + From<u8> + std::ops::Mul + std::ops::AddAssign<<T as std::ops::Mul>::Output>>
+    SparseMatrix<T>
+{
+    // only the stored entries are visited, so the cost scales with nnz rather
+    // than with m * n
+    pub fn multiply_dense(&self, other: &Matrix<T>) -> Result<Matrix<T>, CustomErrors> {
+        if self.n != other.m {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (self.n, other.n),
+                (other.m, other.n),
+            )));
+        }
+
+        let zero_cast: T = 0.into();
+        let n = other.n;
+        let mut new_data: Vec<T> = vec![zero_cast; self.m * n];
+        for i in 0..self.m {
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                let value = self.values[k];
+                let row = self.col_indices[k];
+                for j in 0..n {
+                    new_data[i * n + j] += value * other.data[row * n + j]
+                }
+            }
+        }
+
+        Ok(Matrix {
+            data: new_data,
+            m: self.m,
+            n,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, IndexOutOfBoundsError, MismatchError};
+    use crate::matrix::operations::multiply_matrices;
+    use crate::matrix::Matrix;
+
+    use super::SparseMatrix;
+
+    #[test]
+    fn test_sparse_round_trip() {
+        let dense = Matrix::new(vec![
+            vec![0, 0, 3, 0],
+            vec![1, 0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 2, 0, 4],
+        ])
+        .unwrap();
+
+        let sparse = SparseMatrix::from_dense(&dense);
+        assert_eq!(sparse.nnz(), 4);
+        assert_eq!(sparse.values, vec![3, 1, 2, 4]);
+        assert_eq!(sparse.col_indices, vec![2, 0, 1, 3]);
+        assert_eq!(sparse.row_ptr, vec![0, 1, 2, 2, 4]);
+        assert_eq!(sparse.to_dense(), dense);
+
+        assert_eq!(sparse.get(0, 2).unwrap(), 3);
+        assert_eq!(sparse.get(3, 3).unwrap(), 4);
+        assert_eq!(sparse.get(2, 1).unwrap(), 0);
+        match sparse.get(4, 0) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::IndexOutOfBounds(IndexOutOfBoundsError)),
+        }
+    }
+
+    #[test]
+    fn test_sparse_multiply_dense() {
+        let a = Matrix::new(vec![
+            vec![0.0, 2.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+            vec![1.5, 0.0, -1.0],
+        ])
+        .unwrap();
+        let b = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+
+        let sparse = SparseMatrix::from_dense(&a);
+        assert_eq!(
+            sparse.multiply_dense(&b).unwrap(),
+            multiply_matrices(&a, &b).unwrap()
+        );
+
+        match sparse.multiply_dense(&a.submatrix(0..2, 0..3).unwrap()) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 3), (2, 3)))
+            ),
+        }
+    }
+}
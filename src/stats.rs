@@ -0,0 +1,136 @@
+use crate::error::{CustomErrors, EmptyVectorError};
+use crate::matrix::operations::multiply_matrices;
+use crate::matrix::Matrix;
+
+pub fn column_means(matrix: &Matrix<f64>) -> Vec<f64> {
+    let mut means = vec![0.0; matrix.n];
+    for row in matrix.iter_rows() {
+        for j in 0..matrix.n {
+            means[j] += row[j]
+        }
+    }
+
+    for j in 0..matrix.n {
+        means[j] /= matrix.m as f64
+    }
+    means
+}
+
+// sample variance with an n - 1 denominator. a single row has no spread to
+// estimate, so its variances are reported as 0.0 rather than dividing by zero
+pub fn column_variances(matrix: &Matrix<f64>) -> Vec<f64> {
+    if matrix.m < 2 {
+        return vec![0.0; matrix.n];
+    }
+
+    let means = column_means(matrix);
+    let mut variances = vec![0.0; matrix.n];
+    for row in matrix.iter_rows() {
+        for j in 0..matrix.n {
+            variances[j] += (row[j] - means[j]).powi(2)
+        }
+    }
+
+    for j in 0..matrix.n {
+        variances[j] /= (matrix.m - 1) as f64
+    }
+    variances
+}
+
+pub fn column_stds(matrix: &Matrix<f64>) -> Vec<f64> {
+    let mut stds = column_variances(matrix);
+    for j in 0..stds.len() {
+        stds[j] = stds[j].sqrt()
+    }
+    stds
+}
+
+pub fn covariance_matrix(x: &Matrix<f64>) -> Result<Matrix<f64>, CustomErrors> {
+    // a single row has no spread to estimate a covariance from
+    if x.m < 2 {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    let means = column_means(x);
+    let mut centered = x.clone();
+    for i in 0..x.m {
+        for j in 0..x.n {
+            centered[(i, j)] -= means[j]
+        }
+    }
+
+    let gram = match multiply_matrices(&centered.transpose(), &centered) {
+        Ok(gram) => gram,
+        Err(err) => return Err(err),
+    };
+
+    let denominator = (x.m - 1) as f64;
+    Ok(gram.map(|v| v / denominator))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, EmptyVectorError};
+    use crate::matrix::logic::is_symmetric;
+    use crate::matrix::Matrix;
+
+    use super::{column_means, column_stds, column_variances, covariance_matrix};
+
+    #[test]
+    fn test_column_statistics() {
+        let x = Matrix::new(vec![
+            vec![1.0, 2.0, 5.0],
+            vec![2.0, 4.0, 5.0],
+            vec![3.0, 6.0, 5.0],
+            vec![6.0, 8.0, 5.0],
+        ])
+        .unwrap();
+
+        assert_eq!(column_means(&x), vec![3.0, 5.0, 5.0]);
+        assert_eq!(column_variances(&x), vec![14.0 / 3.0, 20.0 / 3.0, 0.0]);
+
+        let stds = column_stds(&x);
+        assert!((stds[0] - (14.0f64 / 3.0).sqrt()).abs() < 1e-12);
+        assert!((stds[1] - (20.0f64 / 3.0).sqrt()).abs() < 1e-12);
+        assert_eq!(stds[2], 0.0);
+    }
+
+    #[test]
+    fn test_single_row_statistics() {
+        let x = Matrix::new(vec![vec![1.5, -2.0]]).unwrap();
+
+        assert_eq!(column_means(&x), vec![1.5, -2.0]);
+        assert_eq!(column_variances(&x), vec![0.0, 0.0]);
+        assert_eq!(column_stds(&x), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_covariance_matrix() {
+        let x = Matrix::new(vec![
+            vec![1.0, 2.0, 5.0],
+            vec![2.0, 4.0, 3.0],
+            vec![3.0, 6.0, 1.0],
+            vec![6.0, 8.0, 3.0],
+        ])
+        .unwrap();
+
+        let cov = covariance_matrix(&x).unwrap();
+        assert_eq!((cov.m, cov.n), (3, 3));
+        assert!(is_symmetric(&cov));
+
+        // the diagonal matches the column variances
+        let variances = column_variances(&x);
+        for j in 0..3 {
+            assert!((cov[(j, j)] - variances[j]).abs() < 1e-12);
+        }
+
+        // deviations (-2, -1, 0, 3) and (-3, -1, 1, 3) give 16 / 3
+        assert!((cov[(0, 1)] - 16.0 / 3.0).abs() < 1e-12);
+
+        let single = Matrix::new(vec![vec![1.0, 2.0]]).unwrap();
+        match covariance_matrix(&single) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::ops::{Add, AddAssign, Mul, Sub};
 
-use crate::error::{BadTypeError, CustomErrors, EmptyVectorError, MismatchError};
+use crate::error::{BadTypeError, CustomErrors, EmptyVectorError, MismatchError, ZeroNormError};
 
 pub fn add_vec<T: Copy + Add<Output = T>>(
     vec_1: &Vec<T>,
@@ -11,7 +11,7 @@ pub fn add_vec<T: Copy + Add<Output = T>>(
     let mut vec_3: Vec<T> = vec![];
 
     if l1 != l2 {
-        return Err(CustomErrors::Mismatch(MismatchError));
+        return Err(CustomErrors::Mismatch(MismatchError::new((l1, 1), (l2, 1))));
     }
 
     for i in 0..l1 {
@@ -30,7 +30,7 @@ pub fn sub_vec<T: Copy + Sub<Output = T>>(
     let mut vec_3: Vec<T> = vec![];
 
     if l1 != l2 {
-        return Err(CustomErrors::Mismatch(MismatchError));
+        return Err(CustomErrors::Mismatch(MismatchError::new((l1, 1), (l2, 1))));
     }
 
     for i in 0..l1 {
@@ -45,7 +45,10 @@ pub fn multiply_vec<T: Copy + Mul<Output = T>>(
     vec_2: &Vec<T>,
 ) -> Result<Vec<T>, CustomErrors> {
     if vec_1.len() != vec_2.len() {
-        return Err(CustomErrors::Mismatch(MismatchError));
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (vec_1.len(), 1),
+            (vec_2.len(), 1),
+        )));
     }
 
     let mut new_vec: Vec<T> = vec![];
@@ -117,9 +120,7 @@ pub fn stddev<T: Copy + From<u8> + Into<f64> + Add<Output = T> + AddAssign>(
     let mut n = 0.0;
     let mn = match mean(&vec_1) {
         Ok(v) => v,
-        Err(_) => {
-            return Err(CustomErrors::Mismatch(MismatchError));
-        }
+        Err(err) => return Err(err),
     };
 
     let mut sqsum = 0.0;
@@ -146,7 +147,7 @@ pub fn dot_product<
     let l2 = vec_2.len();
 
     if l1 != l2 {
-        return Err(CustomErrors::Mismatch(MismatchError));
+        return Err(CustomErrors::Mismatch(MismatchError::new((l1, 1), (l2, 1))));
     };
 
     if l1 == 0 {
@@ -170,6 +171,140 @@ pub fn dot_product<
     return Ok(sum);
 }
 
+pub fn dot<T: Copy + Mul<Output = T> + AddAssign + From<u8>>(
+    a: &Vec<T>,
+    b: &Vec<T>,
+) -> Result<T, CustomErrors> {
+    if a.len() != b.len() {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (a.len(), 1),
+            (b.len(), 1),
+        )));
+    }
+
+    if a.is_empty() {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    let mut sum: T = 0.into();
+    for i in 0..a.len() {
+        sum += a[i] * b[i]
+    }
+
+    Ok(sum)
+}
+
+pub fn l2_norm(v: &Vec<f64>) -> f64 {
+    let mut sum = 0.0;
+    for i in 0..v.len() {
+        sum += v[i] * v[i]
+    }
+    sum.sqrt()
+}
+
+pub fn l1_norm(v: &Vec<f64>) -> f64 {
+    let mut sum = 0.0;
+    for i in 0..v.len() {
+        sum += v[i].abs()
+    }
+    sum
+}
+
+pub fn linf_norm(v: &Vec<f64>) -> f64 {
+    let mut max = 0.0;
+    for i in 0..v.len() {
+        if v[i].abs() > max {
+            max = v[i].abs()
+        }
+    }
+    max
+}
+
+pub fn scale_vec<T: Copy + Mul<Output = T>>(v: &Vec<T>, scalar: T) -> Vec<T> {
+    let mut scaled: Vec<T> = vec![];
+    for i in 0..v.len() {
+        scaled.push(v[i] * scalar)
+    }
+    scaled
+}
+
+pub fn normalize(v: &Vec<f64>) -> Result<Vec<f64>, CustomErrors> {
+    if v.is_empty() {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    let norm = l2_norm(v);
+    if norm == 0.0 {
+        return Err(CustomErrors::ZeroNorm(ZeroNormError));
+    }
+
+    Ok(scale_vec(v, 1.0 / norm))
+}
+
+// vectors that are (numerically) a combination of the ones before them are
+// skipped, so the result may hold fewer vectors than the input
+pub fn gram_schmidt(vectors: &Vec<Vec<f64>>) -> Result<Vec<Vec<f64>>, CustomErrors> {
+    if vectors.is_empty() || vectors[0].is_empty() {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    let len = vectors[0].len();
+    let tolerance = 1e-10;
+    let mut basis: Vec<Vec<f64>> = vec![];
+    for v in vectors {
+        if v.len() != len {
+            return Err(CustomErrors::Mismatch(MismatchError::new(
+                (len, 1),
+                (v.len(), 1),
+            )));
+        }
+
+        // modified Gram-Schmidt, remove each basis direction in turn
+        let mut u = v.clone();
+        for q in &basis {
+            let projection = match dot(q, &u) {
+                Ok(val) => val,
+                Err(err) => return Err(err),
+            };
+            for i in 0..len {
+                u[i] -= projection * q[i]
+            }
+        }
+
+        // compared against the input vector's own length so that small
+        // but independent vectors are kept
+        let norm = l2_norm(&u);
+        if norm <= tolerance * l2_norm(v) {
+            continue;
+        }
+        basis.push(scale_vec(&u, 1.0 / norm))
+    }
+
+    Ok(basis)
+}
+
+pub fn cross(a: &Vec<f64>, b: &Vec<f64>) -> Result<Vec<f64>, CustomErrors> {
+    if a.len() != 3 {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (3, 1),
+            (a.len(), 1),
+        )));
+    }
+
+    if b.len() != 3 {
+        return Err(CustomErrors::Mismatch(MismatchError::new(
+            (3, 1),
+            (b.len(), 1),
+        )));
+    }
+
+    Ok(vec![
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ])
+}
+
 pub fn magnitude<
     T: Copy
         + From<u8>
@@ -318,3 +453,142 @@ pub fn sub_from_scalar<T: std::ops::Sub<Output = T> + Copy>(scalar: T, vec: &Vec
     }
     new
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{CustomErrors, EmptyVectorError, MismatchError, ZeroNormError};
+
+    use super::{cross, dot, gram_schmidt, l1_norm, l2_norm, linf_norm, normalize, scale_vec};
+
+    #[test]
+    fn test_dot() {
+        assert_eq!(dot(&vec![1, 2, 3], &vec![4, -5, 6]).unwrap(), 12);
+        assert_eq!(dot(&vec![0.5, 2.0], &vec![4.0, 0.25]).unwrap(), 2.5);
+
+        match dot(&vec![1, 2, 3], &vec![1, 2]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 1), (2, 1)))
+            ),
+        }
+
+        let empty: Vec<i32> = vec![];
+        match dot(&empty, &empty) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_scale_vec() {
+        assert_eq!(scale_vec(&vec![1, 2, 3], 2), vec![2, 4, 6]);
+        assert_eq!(scale_vec(&vec![1.5, -2.0], 0.5), vec![0.75, -1.0]);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let unit = normalize(&vec![3.0, 4.0]).unwrap();
+        assert!((l2_norm(&unit) - 1.0).abs() < 1e-12);
+        assert!((unit[0] - 0.6).abs() < 1e-12);
+        assert!((unit[1] - 0.8).abs() < 1e-12);
+
+        match normalize(&vec![0.0, 0.0, 0.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::ZeroNorm(ZeroNormError)),
+        }
+        match normalize(&vec![]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_cross() {
+        let x = vec![1.0, 0.0, 0.0];
+        let y = vec![0.0, 1.0, 0.0];
+        let z = vec![0.0, 0.0, 1.0];
+
+        assert_eq!(cross(&x, &y).unwrap(), z);
+        assert_eq!(cross(&y, &z).unwrap(), x);
+        assert_eq!(cross(&z, &x).unwrap(), y);
+        assert_eq!(cross(&y, &x).unwrap(), vec![0.0, 0.0, -1.0]);
+        assert_eq!(
+            cross(&vec![1.0, 2.0, 3.0], &vec![4.0, 5.0, 6.0]).unwrap(),
+            vec![-3.0, 6.0, -3.0]
+        );
+
+        match cross(&vec![1.0, 2.0], &z) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 1), (2, 1)))
+            ),
+        }
+        match cross(&x, &vec![1.0, 2.0, 3.0, 4.0]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((3, 1), (4, 1)))
+            ),
+        }
+    }
+
+    #[test]
+    fn test_norms() {
+        let v = vec![3.0, 4.0];
+        assert_eq!(l2_norm(&v), 5.0);
+        assert_eq!(l1_norm(&v), 7.0);
+        assert_eq!(linf_norm(&v), 4.0);
+
+        let v = vec![-6.0, 2.0, 3.0];
+        assert_eq!(l2_norm(&v), 7.0);
+        assert_eq!(l1_norm(&v), 11.0);
+        assert_eq!(linf_norm(&v), 6.0);
+
+        let empty: Vec<f64> = vec![];
+        assert_eq!(l2_norm(&empty), 0.0);
+        assert_eq!(l1_norm(&empty), 0.0);
+        assert_eq!(linf_norm(&empty), 0.0);
+    }
+
+    #[test]
+    fn test_gram_schmidt() {
+        let basis = gram_schmidt(&vec![vec![3.0, 1.0, 0.0], vec![2.0, 2.0, 1.0]]).unwrap();
+        assert_eq!(basis.len(), 2);
+        assert!(dot(&basis[0], &basis[1]).unwrap().abs() < 1e-10);
+        for q in &basis {
+            assert!((l2_norm(q) - 1.0).abs() < 1e-10);
+        }
+
+        // the third vector is the sum of the first two
+        let basis = gram_schmidt(&vec![
+            vec![1.0, 0.0, 1.0],
+            vec![0.0, 1.0, 1.0],
+            vec![1.0, 1.0, 2.0],
+        ])
+        .unwrap();
+        assert_eq!(basis.len(), 2);
+
+        let basis = gram_schmidt(&vec![vec![1e-11, 0.0], vec![0.0, 1e-11]]).unwrap();
+        assert_eq!(basis.len(), 2);
+        assert!((basis[0][0] - 1.0).abs() < 1e-12 && (basis[1][1] - 1.0).abs() < 1e-12);
+
+        // dependent at a large scale is still dependent
+        let basis = gram_schmidt(&vec![vec![1e6, 2e6], vec![2e6, 4e6]]).unwrap();
+        assert_eq!(basis.len(), 1);
+
+        match gram_schmidt(&vec![vec![1.0, 2.0], vec![1.0]]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(
+                err,
+                CustomErrors::Mismatch(MismatchError::new((2, 1), (1, 1)))
+            ),
+        }
+
+        match gram_schmidt(&vec![]) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+}
@@ -44,6 +44,52 @@ pub fn zeroes(size: usize) -> Vec<f64> {
     }
     zeros
 }
+pub fn checked_factorial(n: u64) -> Option<u64> {
+    let mut product: u64 = 1;
+    for k in 2..=n {
+        product = match product.checked_mul(k) {
+            Some(product) => product,
+            None => return None,
+        };
+    }
+    Some(product)
+}
+
+// panics when the result doesn't fit in a u64, i.e. for n > 20
+pub fn factorial(n: u64) -> u64 {
+    match checked_factorial(n) {
+        Some(product) => product,
+        None => panic!("{}! overflows u64", n),
+    }
+}
+
+// all k element subsets of 0..n as sorted index vectors, in lexicographic order
+pub fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    if k > n {
+        return vec![];
+    }
+
+    let mut combos: Vec<Vec<usize>> = vec![];
+    let mut indices: Vec<usize> = (0..k).collect();
+    loop {
+        combos.push(indices.clone());
+
+        // find the right-most index that can still move right
+        let mut i = k;
+        while i > 0 && indices[i - 1] == n - k + i - 1 {
+            i -= 1
+        }
+        if i == 0 {
+            return combos;
+        }
+
+        indices[i - 1] += 1;
+        for j in i..k {
+            indices[j] = indices[j - 1] + 1
+        }
+    }
+}
+
 pub fn get_perms(size: usize) -> Vec<Vec<usize>> {
     let mut vec: Vec<usize> = vec![];
     for i in 0..size {
@@ -53,30 +99,33 @@ pub fn get_perms(size: usize) -> Vec<Vec<usize>> {
     heap_permutation(&mut vec, size)
 }
 
+// iterative form of Heap's algorithm, every permutation after the first is
+// a single swap away from the previous one. counters[i] plays the role of
+// the loop index at recursion depth i in the recursive version
 pub fn heap_permutation<T: Copy + std::fmt::Debug>(vec: &mut Vec<T>, size: usize) -> Vec<Vec<T>> {
-    let mut permutations: Vec<Vec<T>> = vec![];
-
-    if size == 1 {
-        return vec![vec.to_vec()];
+    if size == 0 {
+        return vec![];
     }
 
-    for i in 0..size {
-        let heap = heap_permutation(vec, size - 1);
-
-        for p in heap {
-            permutations.push(p)
-        }
+    let total = factorial(size as u64) as usize;
+    let mut permutations: Vec<Vec<T>> = Vec::with_capacity(total);
+    permutations.push(vec[..size].to_vec());
 
-        if size.rem_euclid(2) == 1 {
-            let a = vec[0];
-            let b = vec[size - 1];
-            vec[0] = b;
-            vec[size - 1] = a;
+    let mut counters: Vec<usize> = vec![0; size];
+    let mut i = 1;
+    while i < size {
+        if counters[i] < i {
+            if i % 2 == 0 {
+                vec.swap(0, i)
+            } else {
+                vec.swap(counters[i], i)
+            }
+            permutations.push(vec[..size].to_vec());
+            counters[i] += 1;
+            i = 1;
         } else {
-            let a = vec[i];
-            let b = vec[size - 1];
-            vec[i] = b;
-            vec[size - 1] = a;
+            counters[i] = 0;
+            i += 1;
         }
     }
     permutations
@@ -124,31 +173,84 @@ pub fn create_identity_matrix<T: Copy + From<u8>>(dim: usize) -> Result<Matrix<T
     let cast_zero: T = 0.into();
     let cast_one: T = 1.into();
 
-    let mut rows: Vec<Vec<T>> = vec![];
+    let mut data: Vec<T> = vec![cast_zero; dim * dim];
     for i in 0..dim {
-        let mut matrix_row: Vec<T> = vec![];
+        data[i * dim + i] = cast_one
+    }
+    Ok(Matrix {
+        data,
+        m: dim,
+        n: dim,
+    })
+}
 
-        for j in 0..dim {
-            if j == i {
-                matrix_row.push(cast_one)
-            } else {
-                matrix_row.push(cast_zero)
-            }
-        }
-        rows.push(matrix_row);
+pub fn diag<T: Copy + From<u8>>(values: &Vec<T>) -> Result<Matrix<T>, CustomErrors> {
+    let dim = values.len();
+    if dim == 0 {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+    let cast_zero: T = 0.into();
+
+    let mut data: Vec<T> = vec![cast_zero; dim * dim];
+    for i in 0..dim {
+        data[i * dim + i] = values[i]
     }
     Ok(Matrix {
-        rows,
+        data,
         m: dim,
         n: dim,
     })
 }
 
+pub fn fill<T: Copy>(m: usize, n: usize, value: T) -> Result<Matrix<T>, CustomErrors> {
+    if m == 0 || n == 0 {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    Ok(Matrix {
+        data: vec![value; m * n],
+        m,
+        n,
+    })
+}
+
+pub fn zeros<T: Copy + From<u8>>(m: usize, n: usize) -> Result<Matrix<T>, CustomErrors> {
+    let cast_zero: T = 0.into();
+    fill(m, n, cast_zero)
+}
+
+pub fn ones<T: Copy + From<u8>>(m: usize, n: usize) -> Result<Matrix<T>, CustomErrors> {
+    let cast_one: T = 1.into();
+    fill(m, n, cast_one)
+}
+
+pub fn from_fn<T: Copy, F: Fn(usize, usize) -> T>(
+    m: usize,
+    n: usize,
+    f: F,
+) -> Result<Matrix<T>, CustomErrors> {
+    if m == 0 || n == 0 {
+        return Err(CustomErrors::EmptyVector(EmptyVectorError));
+    }
+
+    let mut data: Vec<T> = Vec::with_capacity(m * n);
+    for i in 0..m {
+        for j in 0..n {
+            data.push(f(i, j))
+        }
+    }
+    Ok(Matrix { data, m, n })
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::error::{CustomErrors, EmptyVectorError};
     use crate::vector::util::{is_all_zeroes, vec_is_diagonalized};
 
-    use super::zeroes;
+    use super::{
+        checked_factorial, combinations, create_identity_matrix, diag, factorial, fill, from_fn,
+        get_perms, get_permutation_sign, ones, zeroes, zeros,
+    };
 
     #[test]
     fn test_all_zeros() {
@@ -188,4 +290,133 @@ mod tests {
         assert_eq!(is_all_zeroes(&vec4), true);
         assert_eq!(is_all_zeroes(&vec5), false);
     }
+
+    #[test]
+    fn test_zeros() {
+        let m = zeros::<f64>(2, 3).unwrap();
+        assert_eq!(m.m, 2);
+        assert_eq!(m.n, 3);
+        assert_eq!(m.rows(), vec![vec![0.0, 0.0, 0.0], vec![0.0, 0.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_ones() {
+        let m = ones::<i32>(3, 1).unwrap();
+        assert_eq!(m.m, 3);
+        assert_eq!(m.n, 1);
+        assert_eq!(m.rows(), vec![vec![1], vec![1], vec![1]]);
+    }
+
+    #[test]
+    fn test_fill() {
+        let m = fill(2, 2, 7.5).unwrap();
+        assert_eq!(m.m, 2);
+        assert_eq!(m.n, 2);
+        assert_eq!(m.rows(), vec![vec![7.5, 7.5], vec![7.5, 7.5]]);
+
+        match fill(0, 2, 7.5) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+        match zeros::<f64>(2, 0) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let hilbert = from_fn(3, 3, |i, j| 1.0 / (i + j + 1) as f64).unwrap();
+        assert_eq!(hilbert.m, 3);
+        assert_eq!(hilbert.n, 3);
+        assert_eq!(hilbert[(0, 0)], 1.0);
+        assert_eq!(hilbert[(1, 2)], 0.25);
+        assert_eq!(hilbert[(2, 2)], 0.2);
+
+        match from_fn(0, 3, |i, j| i + j) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
+
+    #[test]
+    fn test_get_perms() {
+        let perms = get_perms(4);
+        assert_eq!(perms.len(), 24);
+
+        let mut sorted_perms = perms.clone();
+        sorted_perms.sort();
+        sorted_perms.dedup();
+        assert_eq!(sorted_perms.len(), 24);
+
+        for perm in &perms {
+            let mut sorted = perm.clone();
+            sorted.sort();
+            assert_eq!(sorted, vec![0, 1, 2, 3]);
+        }
+
+        // half of the permutations are even and half are odd
+        let mut sign_sum = 0;
+        for perm in perms {
+            sign_sum += get_permutation_sign(perm)
+        }
+        assert_eq!(sign_sum, 0);
+
+        assert_eq!(get_perms(1), vec![vec![0]]);
+        assert_eq!(get_perms(0).len(), 0);
+    }
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial(0), 1);
+        assert_eq!(factorial(5), 120);
+        assert_eq!(checked_factorial(20), Some(2432902008176640000));
+        assert_eq!(checked_factorial(21), None);
+    }
+
+    #[test]
+    fn test_combinations() {
+        let combos = combinations(4, 2);
+        assert_eq!(combos.len(), 6);
+        assert_eq!(
+            combos,
+            vec![
+                vec![0, 1],
+                vec![0, 2],
+                vec![0, 3],
+                vec![1, 2],
+                vec![1, 3],
+                vec![2, 3]
+            ]
+        );
+
+        assert_eq!(combinations(5, 3).len(), 10);
+        assert_eq!(combinations(3, 0), vec![Vec::<usize>::new()]);
+        assert_eq!(combinations(3, 3), vec![vec![0, 1, 2]]);
+        assert_eq!(combinations(2, 3).len(), 0);
+    }
+
+    #[test]
+    fn test_diag() {
+        let d = diag(&vec![1, 2, 3]).unwrap();
+        assert_eq!((d.m, d.n), (3, 3));
+        for i in 0..3 {
+            for j in 0..3 {
+                if i == j {
+                    assert_eq!(d[(i, j)], i as i32 + 1);
+                } else {
+                    assert_eq!(d[(i, j)], 0);
+                }
+            }
+        }
+
+        let ones = vec![1.0; 4];
+        assert_eq!(diag(&ones).unwrap(), create_identity_matrix(4).unwrap());
+
+        let empty: Vec<f64> = vec![];
+        match diag(&empty) {
+            Ok(_) => assert!(false),
+            Err(err) => assert_eq!(err, CustomErrors::EmptyVector(EmptyVectorError)),
+        }
+    }
 }